@@ -0,0 +1,1008 @@
+use gtk::{
+    cairo, glib, prelude::*, Box, Button, DrawingArea, DropDown, Entry, GestureDrag, Label,
+    ListBox, Orientation, ScrolledWindow, SelectionMode, SpinButton, StringList, Switch,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::widgets::WidgetBuilder;
+
+pub const ANIMATION_TREE: &[(&str, Option<&str>)] = &[
+    ("global", None),
+    ("windows", Some("global")),
+    ("windowsIn", Some("windows")),
+    ("windowsOut", Some("windows")),
+    ("windowsMove", Some("windows")),
+    ("layers", Some("global")),
+    ("layersIn", Some("layers")),
+    ("layersOut", Some("layers")),
+    ("fade", Some("global")),
+    ("fadeIn", Some("fade")),
+    ("fadeOut", Some("fade")),
+    ("fadeSwitch", Some("fade")),
+    ("fadeShadow", Some("fade")),
+    ("fadeDim", Some("fade")),
+    ("fadeLayers", Some("fade")),
+    ("fadeLayersIn", Some("fadeLayers")),
+    ("fadeLayersOut", Some("fadeLayers")),
+    ("fadePopups", Some("fade")),
+    ("fadePopupsIn", Some("fadePopups")),
+    ("fadePopupsOut", Some("fadePopups")),
+    ("fadeDpms", Some("fade")),
+    ("border", Some("global")),
+    ("borderangle", Some("global")),
+    ("workspaces", Some("global")),
+    ("workspacesIn", Some("workspaces")),
+    ("workspacesOut", Some("workspaces")),
+    ("specialWorkspace", Some("workspaces")),
+    ("specialWorkspaceIn", Some("specialWorkspace")),
+    ("specialWorkspaceOut", Some("specialWorkspace")),
+    ("zoomFactor", Some("global")),
+    ("monitorAdded", Some("global")),
+];
+
+const DEFAULT_CURVE: [f64; 4] = [0.0, 0.75, 0.15, 1.0];
+
+const PREVIEW_STYLES: [&str; 14] = [
+    "slide",
+    "slide top",
+    "slide bottom",
+    "slide right",
+    "slidevert",
+    "popin",
+    "popin 80%",
+    "popin 50%",
+    "gnomed",
+    "fade",
+    "slidefade",
+    "slidefade 40%",
+    "slidefadevert",
+    "slidefadevert 40%",
+];
+
+fn parent_of(name: &str) -> Option<&'static str> {
+    ANIMATION_TREE
+        .iter()
+        .find(|(node, _)| *node == name)
+        .and_then(|(_, parent)| *parent)
+}
+
+fn depth_of(name: &str) -> usize {
+    let mut depth = 0;
+    let mut current = parent_of(name);
+    while let Some(parent) = current {
+        depth += 1;
+        current = parent_of(parent);
+    }
+    depth
+}
+
+pub fn style_options(name: &str) -> &'static [&'static str] {
+    if name.starts_with("windows") {
+        &[
+            "",
+            "slide",
+            "slide top",
+            "slide bottom",
+            "slide left",
+            "slide right",
+            "popin",
+            "popin 80%",
+            "gnomed",
+        ]
+    } else if name.starts_with("layers") {
+        &[
+            "",
+            "slide",
+            "slide top",
+            "slide bottom",
+            "slide left",
+            "slide right",
+            "popin",
+            "fade",
+        ]
+    } else if name.starts_with("workspaces") || name.starts_with("specialWorkspace") {
+        &[
+            "",
+            "slide",
+            "slidevert",
+            "fade",
+            "slidefade",
+            "slidefade 20%",
+            "slidefadevert",
+            "slidefadevert 20%",
+        ]
+    } else if name == "borderangle" {
+        &["", "once", "loop"]
+    } else {
+        &[""]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bezier {
+    pub name: String,
+    pub points: [f64; 4],
+}
+
+impl Bezier {
+    pub fn parse(value: &str) -> Self {
+        let mut parts = value.split(',').map(str::trim);
+        let name = parts.next().unwrap_or_default().to_string();
+        let mut points = [0.0, 0.0, 1.0, 1.0];
+        for point in points.iter_mut() {
+            if let Some(value) = parts.next().and_then(|p| p.parse().ok()) {
+                *point = value;
+            }
+        }
+        Self { name, points }
+    }
+
+    pub fn value(&self) -> String {
+        let points: Vec<String> = self.points.iter().map(|p| format_number(*p)).collect();
+        format!("{}, {}", self.name, points.join(", "))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    pub name: String,
+    pub enabled: bool,
+    pub speed: f64,
+    pub curve: String,
+    pub style: String,
+}
+
+impl Animation {
+    pub fn global_default() -> Self {
+        Self {
+            name: "global".to_string(),
+            enabled: true,
+            speed: 8.0,
+            curve: "default".to_string(),
+            style: String::new(),
+        }
+    }
+
+    pub fn parse(value: &str) -> Self {
+        let parts: Vec<&str> = value.splitn(5, ',').map(str::trim).collect();
+        Self {
+            name: parts[0].to_string(),
+            enabled: parts.get(1).is_none_or(|p| *p != "0"),
+            speed: parts.get(2).and_then(|p| p.parse().ok()).unwrap_or(8.0),
+            curve: parts.get(3).unwrap_or(&"default").to_string(),
+            style: parts.get(4).unwrap_or(&"").to_string(),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        let mut parts = vec![
+            self.name.clone(),
+            if self.enabled { "1" } else { "0" }.to_string(),
+            format_number(self.speed),
+            self.curve.clone(),
+        ];
+        if !self.style.is_empty() {
+            parts.push(self.style.clone());
+        }
+        parts.join(", ")
+    }
+}
+
+pub fn bezier_point(points: &[f64; 4], t: f64) -> (f64, f64) {
+    let u = 1.0 - t;
+    let x = 3.0 * u * u * t * points[0] + 3.0 * u * t * t * points[2] + t * t * t;
+    let y = 3.0 * u * u * t * points[1] + 3.0 * u * t * t * points[3] + t * t * t;
+    (x, y)
+}
+
+pub fn bezier_ease(points: &[f64; 4], progress: f64) -> f64 {
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..30 {
+        let middle = (low + high) / 2.0;
+        if bezier_point(points, middle).0 < progress {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    bezier_point(points, (low + high) / 2.0).1
+}
+
+fn format_number(value: f64) -> String {
+    let rounded = (value * 1000.0).round() / 1000.0;
+    format!("{}", rounded)
+}
+
+struct NodeRow {
+    name: &'static str,
+    status: Label,
+    enabled: Switch,
+    speed: SpinButton,
+    curve: DropDown,
+    style: DropDown,
+    override_button: Button,
+}
+
+#[derive(Clone)]
+pub struct AnimationsPage {
+    pub scrolled_window: ScrolledWindow,
+    tree: ListBox,
+    bezier_list: ListBox,
+    editor: DrawingArea,
+    preview: DrawingArea,
+    preview_style: DropDown,
+    preview_speed: SpinButton,
+    compare_curve: DropDown,
+    preview_start: Rc<RefCell<Option<i64>>>,
+    animations: Rc<RefCell<Vec<Directive>>>,
+    beziers: Rc<RefCell<Vec<Directive>>>,
+    node_rows: Rc<RefCell<Vec<NodeRow>>>,
+    point_spins: Rc<RefCell<Vec<[SpinButton; 4]>>>,
+    selected_bezier: Rc<RefCell<Option<usize>>>,
+    drag_point: Rc<RefCell<Option<(usize, f64, f64)>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl AnimationsPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Animation Tree",
+            "Nodes without their own line inherit from their parent.",
+            first_section.clone(),
+        );
+
+        let tree = ListBox::new();
+        tree.set_selection_mode(SelectionMode::None);
+        container.append(&tree);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Bezier Curves",
+            "Select a curve and drag its control points to edit it.",
+            first_section.clone(),
+        );
+
+        let bezier_list = ListBox::new();
+        bezier_list.set_selection_mode(SelectionMode::Single);
+        container.append(&bezier_list);
+
+        let add_button = Button::with_label("Add Bezier Curve");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        let editor = DrawingArea::new();
+        editor.set_content_width(320);
+        editor.set_content_height(320);
+        editor.set_valign(gtk::Align::Start);
+
+        let preview = DrawingArea::new();
+        preview.set_content_width(320);
+        preview.set_content_height(240);
+        preview.set_hexpand(true);
+
+        let preview_style = DropDown::new(
+            Some(StringList::new(&PREVIEW_STYLES)),
+            None::<gtk::Expression>,
+        );
+        let preview_speed = SpinButton::with_range(0.1, 100.0, 0.1);
+        preview_speed.set_digits(1);
+        preview_speed.set_value(8.0);
+        preview_speed.set_tooltip_text(Some("Speed in deciseconds"));
+        let compare_curve = DropDown::new(
+            Some(StringList::new(&["none", "default"])),
+            None::<gtk::Expression>,
+        );
+
+        let preview_controls = Box::new(Orientation::Horizontal, 5);
+        for (label, widget) in [
+            ("Style", preview_style.upcast_ref::<gtk::Widget>()),
+            ("Speed", preview_speed.upcast_ref()),
+            ("Compare with", compare_curve.upcast_ref()),
+        ] {
+            let label = Label::new(Some(label));
+            label.set_opacity(0.7);
+            preview_controls.append(&label);
+            preview_controls.append(widget);
+        }
+
+        let preview_box = Box::new(Orientation::Vertical, 5);
+        preview_box.append(&preview);
+        preview_box.append(&preview_controls);
+
+        let editor_box = Box::new(Orientation::Horizontal, 10);
+        editor_box.set_margin_top(10);
+        editor_box.append(&editor);
+        editor_box.append(&preview_box);
+        container.append(&editor_box);
+
+        let page = Self {
+            scrolled_window,
+            tree,
+            bezier_list,
+            editor,
+            preview,
+            preview_style,
+            preview_speed,
+            compare_curve,
+            preview_start: Rc::new(RefCell::new(None)),
+            animations: Rc::new(RefCell::new(Vec::new())),
+            beziers: Rc::new(RefCell::new(Vec::new())),
+            node_rows: Rc::new(RefCell::new(Vec::new())),
+            point_spins: Rc::new(RefCell::new(Vec::new())),
+            selected_bezier: Rc::new(RefCell::new(None)),
+            drag_point: Rc::new(RefCell::new(None)),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        page.editor.set_draw_func(move |_, cr, width, height| {
+            page_clone.draw_editor(cr, width as f64, height as f64);
+        });
+
+        let drag = GestureDrag::new();
+        let page_clone = page.clone();
+        drag.connect_drag_begin(move |_, x, y| {
+            page_clone.editor_drag_begin(x, y);
+        });
+        let page_clone = page.clone();
+        drag.connect_drag_update(move |_, offset_x, offset_y| {
+            page_clone.editor_drag_update(offset_x, offset_y);
+        });
+        let page_clone = page.clone();
+        drag.connect_drag_end(move |_, _, _| {
+            *page_clone.drag_point.borrow_mut() = None;
+        });
+        page.editor.add_controller(drag);
+
+        let page_clone = page.clone();
+        page.preview.set_draw_func(move |area, cr, width, height| {
+            let now = area
+                .frame_clock()
+                .map(|clock| clock.frame_time())
+                .unwrap_or(0);
+            page_clone.draw_preview(cr, width as f64, height as f64, now);
+        });
+        page.preview.add_tick_callback(|area, _| {
+            area.queue_draw();
+            glib::ControlFlow::Continue
+        });
+
+        let page_clone = page.clone();
+        page.preview_style.connect_selected_notify(move |_| {
+            *page_clone.preview_start.borrow_mut() = None;
+        });
+        let page_clone = page.clone();
+        page.preview_speed.connect_value_changed(move |_| {
+            *page_clone.preview_start.borrow_mut() = None;
+        });
+
+        let page_clone = page.clone();
+        page.bezier_list.connect_row_selected(move |_, row| {
+            *page_clone.selected_bezier.borrow_mut() = row.map(|row| row.index() as usize);
+            *page_clone.preview_start.borrow_mut() = None;
+            page_clone.editor.queue_draw();
+        });
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let count = page_clone.beziers.borrow().len();
+            let bezier = Bezier {
+                name: format!("curve{}", count + 1),
+                points: [0.25, 0.1, 0.25, 1.0],
+            };
+            page_clone.beziers.borrow_mut().push(Directive::new(
+                "bezier",
+                &bezier.value(),
+                0,
+                None,
+            ));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+            if let Some(row) = page_clone.bezier_list.row_at_index(count as i32) {
+                page_clone.bezier_list.select_row(Some(&row));
+            }
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.animations.borrow_mut() = directives::collect(config, &|k| k == "animation");
+        *self.beziers.borrow_mut() = directives::collect(config, &|k| k == "bezier");
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &|k| k == "bezier", &self.beziers.borrow());
+            directives::apply(config, &|k| k == "animation", &self.animations.borrow());
+        }
+    }
+
+    fn refresh(&self) {
+        self.refresh_tree();
+        self.refresh_beziers();
+    }
+
+    fn curve_names(&self) -> Vec<String> {
+        let mut names = vec!["default".to_string()];
+        for directive in self.beziers.borrow().iter() {
+            let name = Bezier::parse(&directive.value).name;
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    fn own_index(&self, name: &str) -> Option<usize> {
+        self.animations
+            .borrow()
+            .iter()
+            .rposition(|d| d.submap.is_none() && Animation::parse(&d.value).name == name)
+    }
+
+    fn effective(&self, name: &str) -> (Animation, Option<String>) {
+        let mut current = Some(name);
+        while let Some(node) = current {
+            if let Some(index) = self.own_index(node) {
+                let animation = Animation::parse(&self.animations.borrow()[index].value);
+                let source = (node != name).then(|| node.to_string());
+                return (animation, source);
+            }
+            current = parent_of(node);
+        }
+        (
+            Animation::global_default(),
+            Some("Hyprland defaults".to_string()),
+        )
+    }
+
+    fn update_animation(&self, name: &str, edit: impl Fn(&mut Animation)) {
+        if let Some(index) = self.own_index(name) {
+            if let Some(directive) = self.animations.borrow_mut().get_mut(index) {
+                let mut animation = Animation::parse(&directive.value);
+                edit(&mut animation);
+                directive.value = animation.value();
+            }
+            *self.changed.borrow_mut() = true;
+            self.sync_tree();
+        }
+    }
+
+    fn sync_tree(&self) {
+        let curves = self.curve_names();
+        for row in self.node_rows.borrow().iter() {
+            let (animation, source) = self.effective(row.name);
+            let overridden = source.is_none();
+
+            row.status.set_text(&match &source {
+                Some(source) => format!("inherited from {}", source),
+                None => "overridden".to_string(),
+            });
+            row.override_button
+                .set_label(if overridden { "Reset" } else { "Override" });
+
+            for widget in [
+                row.enabled.upcast_ref::<gtk::Widget>(),
+                row.speed.upcast_ref(),
+                row.curve.upcast_ref(),
+                row.style.upcast_ref(),
+            ] {
+                widget.set_sensitive(overridden);
+            }
+
+            if !overridden {
+                row.enabled.set_active(animation.enabled);
+                row.speed.set_value(animation.speed);
+                if let Some(position) = curves.iter().position(|c| *c == animation.curve) {
+                    row.curve.set_selected(position as u32);
+                }
+                if let Some(position) = style_options(row.name)
+                    .iter()
+                    .position(|s| *s == animation.style)
+                {
+                    row.style.set_selected(position as u32);
+                }
+            }
+        }
+    }
+
+    fn refresh_tree(&self) {
+        while let Some(row) = self.tree.first_child() {
+            self.tree.remove(&row);
+        }
+        self.node_rows.borrow_mut().clear();
+
+        let curves = self.curve_names();
+        for (name, _) in ANIMATION_TREE {
+            let (animation, _) = self.effective(name);
+
+            let hbox = Box::new(Orientation::Horizontal, 5);
+            hbox.set_margin_start(5 + 20 * depth_of(name) as i32);
+            hbox.set_margin_end(5);
+            hbox.set_margin_top(5);
+            hbox.set_margin_bottom(5);
+
+            let name_label = Label::new(Some(name));
+            name_label.set_xalign(0.0);
+            name_label.set_width_chars(20 - 3 * depth_of(name) as i32);
+
+            let status = Label::new(None);
+            status.set_opacity(0.7);
+            status.set_hexpand(true);
+            status.set_xalign(0.0);
+
+            let enabled = Switch::new();
+            enabled.set_valign(gtk::Align::Center);
+            enabled.set_active(animation.enabled);
+
+            let speed = SpinButton::with_range(0.1, 100.0, 0.1);
+            speed.set_digits(1);
+            speed.set_value(animation.speed);
+            speed.set_tooltip_text(Some("Speed in deciseconds"));
+
+            let mut curve_items: Vec<&str> = curves.iter().map(String::as_str).collect();
+            if !curve_items.contains(&animation.curve.as_str()) {
+                curve_items.push(&animation.curve);
+            }
+            let curve = DropDown::new(Some(StringList::new(&curve_items)), None::<gtk::Expression>);
+            if let Some(position) = curve_items.iter().position(|c| *c == animation.curve) {
+                curve.set_selected(position as u32);
+            }
+
+            let mut style_items: Vec<&str> = style_options(name).to_vec();
+            if !style_items.contains(&animation.style.as_str()) {
+                style_items.push(&animation.style);
+            }
+            let style = DropDown::new(Some(StringList::new(&style_items)), None::<gtk::Expression>);
+            if let Some(position) = style_items.iter().position(|s| *s == animation.style) {
+                style.set_selected(position as u32);
+            }
+
+            let override_button = Button::with_label("Override");
+
+            hbox.append(&name_label);
+            hbox.append(&status);
+            hbox.append(&enabled);
+            hbox.append(&speed);
+            hbox.append(&curve);
+            hbox.append(&style);
+            hbox.append(&override_button);
+            self.tree.append(&hbox);
+
+            let page = self.clone();
+            enabled.connect_state_set(move |_, state| {
+                page.update_animation(name, |a| a.enabled = state);
+                glib::Propagation::Proceed
+            });
+
+            let page = self.clone();
+            speed.connect_value_changed(move |sb| {
+                let value = sb.value();
+                page.update_animation(name, |a| a.speed = value);
+            });
+
+            let page = self.clone();
+            curve.connect_selected_notify(move |dd| {
+                let value = selected_string(dd);
+                page.update_animation(name, |a| a.curve = value.clone());
+            });
+
+            let page = self.clone();
+            style.connect_selected_notify(move |dd| {
+                let value = selected_string(dd);
+                page.update_animation(name, |a| a.style = value.clone());
+            });
+
+            let page = self.clone();
+            override_button.connect_clicked(move |_| {
+                match page.own_index(name) {
+                    Some(index) => {
+                        page.animations.borrow_mut().remove(index);
+                    }
+                    None => {
+                        let (mut animation, _) = page.effective(name);
+                        animation.name = name.to_string();
+                        if !style_options(name).contains(&animation.style.as_str()) {
+                            animation.style = String::new();
+                        }
+                        page.animations.borrow_mut().push(Directive::new(
+                            "animation",
+                            &animation.value(),
+                            0,
+                            None,
+                        ));
+                    }
+                }
+                *page.changed.borrow_mut() = true;
+                page.sync_tree();
+            });
+
+            self.node_rows.borrow_mut().push(NodeRow {
+                name,
+                status,
+                enabled,
+                speed,
+                curve,
+                style,
+                override_button,
+            });
+        }
+
+        self.sync_tree();
+    }
+
+    fn refresh_beziers(&self) {
+        let selected = *self.selected_bezier.borrow();
+        while let Some(row) = self.bezier_list.first_child() {
+            self.bezier_list.remove(&row);
+        }
+        self.point_spins.borrow_mut().clear();
+
+        let count = self.beziers.borrow().len();
+        for index in 0..count {
+            let bezier = Bezier::parse(&self.beziers.borrow()[index].value);
+
+            let hbox = Box::new(Orientation::Horizontal, 5);
+            hbox.set_margin_start(5);
+            hbox.set_margin_end(5);
+            hbox.set_margin_top(5);
+            hbox.set_margin_bottom(5);
+
+            let name_entry = Entry::new();
+            name_entry.set_text(&bezier.name);
+            name_entry.set_hexpand(true);
+            hbox.append(&name_entry);
+
+            let spins = [0, 1, 2, 3].map(|point| {
+                let range = if point % 2 == 0 {
+                    (0.0, 1.0)
+                } else {
+                    (-1.0, 2.0)
+                };
+                let spin = SpinButton::with_range(range.0, range.1, 0.01);
+                spin.set_digits(3);
+                spin.set_value(bezier.points[point]);
+                hbox.append(&spin);
+
+                let page = self.clone();
+                spin.connect_value_changed(move |sb| {
+                    let value = sb.value();
+                    page.update_bezier(index, |b| b.points[point] = value);
+                    page.editor.queue_draw();
+                });
+                spin
+            });
+            self.point_spins.borrow_mut().push(spins);
+
+            let delete_button = Button::from_icon_name("user-trash-symbolic");
+            delete_button.set_has_frame(false);
+            hbox.append(&delete_button);
+
+            self.bezier_list.append(&hbox);
+
+            let page = self.clone();
+            name_entry.connect_changed(move |entry| {
+                let name = entry.text().to_string();
+                page.update_bezier(index, |b| b.name = name.clone());
+            });
+
+            let page = self.clone();
+            name_entry.connect_activate(move |_| {
+                page.refresh_tree();
+            });
+
+            let page = self.clone();
+            delete_button.connect_clicked(move |_| {
+                page.beziers.borrow_mut().remove(index);
+                *page.selected_bezier.borrow_mut() = None;
+                *page.changed.borrow_mut() = true;
+                page.refresh();
+            });
+        }
+
+        if let Some(row) = selected.and_then(|index| self.bezier_list.row_at_index(index as i32)) {
+            self.bezier_list.select_row(Some(&row));
+        }
+        self.editor.queue_draw();
+
+        let compared = selected_string(&self.compare_curve);
+        let mut names = vec!["none".to_string()];
+        names.extend(self.curve_names());
+        let items: Vec<&str> = names.iter().map(String::as_str).collect();
+        self.compare_curve.set_model(Some(&StringList::new(&items)));
+        if let Some(position) = items.iter().position(|name| *name == compared) {
+            self.compare_curve.set_selected(position as u32);
+        }
+    }
+
+    fn curve_points(&self, name: &str) -> Option<[f64; 4]> {
+        self.beziers
+            .borrow()
+            .iter()
+            .map(|directive| Bezier::parse(&directive.value))
+            .find(|bezier| bezier.name == name)
+            .map(|bezier| bezier.points)
+            .or(match name {
+                "default" => Some(DEFAULT_CURVE),
+                "linear" => Some([0.0, 0.0, 1.0, 1.0]),
+                _ => None,
+            })
+    }
+
+    fn draw_preview(&self, cr: &cairo::Context, width: f64, height: f64, now: i64) {
+        let start = *self.preview_start.borrow();
+        let start = match start {
+            Some(start) => start,
+            None => {
+                *self.preview_start.borrow_mut() = Some(now);
+                now
+            }
+        };
+
+        let duration = self.preview_speed.value() * 100.0;
+        let hold = 500.0;
+        let cycle = 2.0 * (duration + hold);
+        let elapsed = ((now - start) as f64 / 1000.0) % cycle;
+        let (progress, entering) = if elapsed < duration {
+            (elapsed / duration, true)
+        } else if elapsed < duration + hold {
+            (1.0, true)
+        } else if elapsed < 2.0 * duration + hold {
+            ((elapsed - duration - hold) / duration, false)
+        } else {
+            (1.0, false)
+        };
+
+        let style = selected_string(&self.preview_style);
+        let mut lanes = vec![(
+            self.selected_points().unwrap_or(DEFAULT_CURVE),
+            (0.35, 0.55, 0.85),
+            match *self.selected_bezier.borrow() {
+                Some(_) => "selected curve".to_string(),
+                None => "default".to_string(),
+            },
+        )];
+        let compared = selected_string(&self.compare_curve);
+        if let Some(points) = self.curve_points(&compared) {
+            lanes.push((points, (0.6, 0.6, 0.6), compared));
+        }
+
+        let lane_height = height / lanes.len() as f64;
+        for (lane, (points, color, name)) in lanes.iter().enumerate() {
+            let eased = bezier_ease(points, progress);
+            let visibility = if entering { eased } else { 1.0 - eased };
+            let top = lane as f64 * lane_height;
+
+            cr.save().ok();
+            cr.rectangle(0.0, top, width, lane_height);
+            cr.clip();
+            cr.set_source_rgba(0.5, 0.5, 0.5, 0.1);
+            cr.rectangle(0.0, top, width, lane_height);
+            let _ = cr.fill();
+
+            draw_sample(
+                cr,
+                (0.0, top, width, lane_height),
+                &style,
+                visibility,
+                *color,
+            );
+
+            cr.set_source_rgba(0.5, 0.5, 0.5, 1.0);
+            cr.set_font_size(11.0);
+            cr.move_to(8.0, top + 16.0);
+            let _ = cr.show_text(name);
+            cr.restore().ok();
+        }
+    }
+
+    fn update_bezier(&self, index: usize, edit: impl Fn(&mut Bezier)) {
+        if let Some(directive) = self.beziers.borrow_mut().get_mut(index) {
+            let mut bezier = Bezier::parse(&directive.value);
+            edit(&mut bezier);
+            directive.value = bezier.value();
+        }
+        *self.changed.borrow_mut() = true;
+    }
+
+    fn selected_points(&self) -> Option<[f64; 4]> {
+        let index = (*self.selected_bezier.borrow())?;
+        let beziers = self.beziers.borrow();
+        Some(Bezier::parse(&beziers.get(index)?.value).points)
+    }
+
+    fn to_screen(width: f64, height: f64, x: f64, y: f64) -> (f64, f64) {
+        let margin = 20.0;
+        let graph_height = (height - 2.0 * margin) / 2.0;
+        (
+            margin + x * (width - 2.0 * margin),
+            margin + graph_height * 1.5 - y * graph_height,
+        )
+    }
+
+    fn draw_editor(&self, cr: &cairo::Context, width: f64, height: f64) {
+        let (x0, y0) = Self::to_screen(width, height, 0.0, 0.0);
+        let (x1, y1) = Self::to_screen(width, height, 1.0, 1.0);
+
+        cr.set_source_rgba(0.5, 0.5, 0.5, 0.15);
+        cr.rectangle(x0, y1, x1 - x0, y0 - y1);
+        let _ = cr.fill();
+
+        cr.set_source_rgba(0.5, 0.5, 0.5, 0.5);
+        cr.set_line_width(1.0);
+        cr.move_to(x0, y0);
+        cr.line_to(x1, y1);
+        let _ = cr.stroke();
+
+        let points = match self.selected_points() {
+            Some(points) => points,
+            None => {
+                cr.set_font_size(13.0);
+                cr.move_to(x0 + 10.0, (y0 + y1) / 2.0);
+                let _ = cr.show_text("Select a curve to edit it");
+                return;
+            }
+        };
+
+        let (p1x, p1y) = Self::to_screen(width, height, points[0], points[1]);
+        let (p2x, p2y) = Self::to_screen(width, height, points[2], points[3]);
+
+        cr.set_source_rgba(0.6, 0.6, 0.6, 0.8);
+        cr.move_to(x0, y0);
+        cr.line_to(p1x, p1y);
+        cr.move_to(x1, y1);
+        cr.line_to(p2x, p2y);
+        let _ = cr.stroke();
+
+        cr.set_source_rgb(0.35, 0.55, 0.85);
+        cr.set_line_width(3.0);
+        cr.move_to(x0, y0);
+        cr.curve_to(p1x, p1y, p2x, p2y, x1, y1);
+        let _ = cr.stroke();
+
+        for (x, y) in [(p1x, p1y), (p2x, p2y)] {
+            cr.arc(x, y, 7.0, 0.0, std::f64::consts::TAU);
+            cr.set_source_rgb(0.9, 0.5, 0.2);
+            let _ = cr.fill();
+        }
+    }
+
+    fn editor_drag_begin(&self, x: f64, y: f64) {
+        let points = match self.selected_points() {
+            Some(points) => points,
+            None => return,
+        };
+        let width = self.editor.width() as f64;
+        let height = self.editor.height() as f64;
+
+        for point in 0..2 {
+            let (px, py) = Self::to_screen(width, height, points[point * 2], points[point * 2 + 1]);
+            if (px - x).hypot(py - y) < 12.0 {
+                *self.drag_point.borrow_mut() = Some((point, px, py));
+                return;
+            }
+        }
+    }
+
+    fn editor_drag_update(&self, offset_x: f64, offset_y: f64) {
+        let (point, start_x, start_y) = match *self.drag_point.borrow() {
+            Some(drag) => drag,
+            None => return,
+        };
+        let index = match *self.selected_bezier.borrow() {
+            Some(index) => index,
+            None => return,
+        };
+
+        let width = self.editor.width() as f64;
+        let height = self.editor.height() as f64;
+        let (x0, y0) = Self::to_screen(width, height, 0.0, 0.0);
+        let (x1, y1) = Self::to_screen(width, height, 1.0, 1.0);
+
+        let x = ((start_x + offset_x - x0) / (x1 - x0)).clamp(0.0, 1.0);
+        let y = ((start_y + offset_y - y0) / (y1 - y0)).clamp(-1.0, 2.0);
+
+        if let Some(spins) = self.point_spins.borrow().get(index) {
+            spins[point * 2].set_value((x * 1000.0).round() / 1000.0);
+            spins[point * 2 + 1].set_value((y * 1000.0).round() / 1000.0);
+        }
+    }
+}
+
+fn draw_sample(
+    cr: &cairo::Context,
+    lane: (f64, f64, f64, f64),
+    style: &str,
+    visibility: f64,
+    color: (f64, f64, f64),
+) {
+    let (lane_x, lane_y, lane_width, lane_height) = lane;
+    let width = lane_width * 0.4;
+    let height = lane_height * 0.5;
+    let target_x = lane_x + (lane_width - width) / 2.0;
+    let target_y = lane_y + (lane_height - height) / 2.0;
+
+    let (kind, argument) = style.split_once(' ').unwrap_or((style, ""));
+    let percent = argument
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .map(|p| p / 100.0);
+
+    let (mut x, mut y, mut w, mut h, mut alpha) = (target_x, target_y, width, height, 1.0);
+    match kind {
+        "slide" | "slidevert" => {
+            let direction = if kind == "slidevert" { "top" } else { argument };
+            let (start_x, start_y) = match direction {
+                "top" => (target_x, lane_y - height),
+                "bottom" => (target_x, lane_y + lane_height),
+                "right" => (lane_x + lane_width, target_y),
+                _ => (lane_x - width, target_y),
+            };
+            x = start_x + (target_x - start_x) * visibility;
+            y = start_y + (target_y - start_y) * visibility;
+        }
+        "slidefade" | "slidefadevert" => {
+            let distance = percent.unwrap_or(0.2);
+            if kind == "slidefadevert" {
+                y = target_y - lane_height * distance * (1.0 - visibility);
+            } else {
+                x = target_x - lane_width * distance * (1.0 - visibility);
+            }
+            alpha = visibility;
+        }
+        "popin" => {
+            let scale = percent.unwrap_or(0.8);
+            let factor = scale + (1.0 - scale) * visibility;
+            w = width * factor;
+            h = height * factor;
+            x = target_x + (width - w) / 2.0;
+            y = target_y + (height - h) / 2.0;
+            alpha = visibility;
+        }
+        "gnomed" => {
+            h = height * visibility.max(0.0);
+            y = target_y + (height - h) / 2.0;
+            alpha = visibility;
+        }
+        _ => alpha = visibility,
+    }
+
+    cr.set_source_rgba(color.0, color.1, color.2, alpha.clamp(0.0, 1.0));
+    cr.rectangle(x, y, w, h);
+    let _ = cr.fill();
+}
+
+fn selected_string(dropdown: &DropDown) -> String {
+    dropdown
+        .selected_item()
+        .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+        .map(|item| item.string().to_string())
+        .unwrap_or_default()
+}
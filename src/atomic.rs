@@ -0,0 +1,114 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+struct StagedWrite {
+    target: PathBuf,
+    temp: PathBuf,
+    original: Option<Vec<u8>>,
+}
+
+fn with_path(path: &Path, error: io::Error) -> io::Error {
+    io::Error::new(error.kind(), format!("{}: {}", path.display(), error))
+}
+
+fn resolve_target(path: &Path) -> io::Result<PathBuf> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => fs::canonicalize(path),
+        _ => Ok(path.to_path_buf()),
+    }
+}
+
+fn temp_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{}.hyprgui-{}.tmp", name, std::process::id()))
+}
+
+fn sync_dir(path: &Path) {
+    if let Some(dir) = path.parent() {
+        if let Ok(dir) = File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+}
+
+fn stage(path: &Path, contents: &[u8]) -> io::Result<StagedWrite> {
+    let target = resolve_target(path)?;
+    let temp = temp_path(&target);
+    let original = match fs::read(&target) {
+        Ok(original) => Some(original),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+
+    let result = (|| {
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&temp)?;
+        file.write_all(contents)?;
+        if let Ok(metadata) = fs::metadata(&target) {
+            file.set_permissions(metadata.permissions())?;
+        }
+        file.sync_all()
+    })();
+
+    match result {
+        Ok(()) => Ok(StagedWrite {
+            target,
+            temp,
+            original,
+        }),
+        Err(e) => {
+            let _ = fs::remove_file(&temp);
+            Err(e)
+        }
+    }
+}
+
+fn roll_back(committed: &[StagedWrite]) {
+    for staged in committed.iter().rev() {
+        match &staged.original {
+            Some(original) => {
+                if let Ok(restore) = stage(&staged.target, original) {
+                    let _ = fs::rename(&restore.temp, &restore.target);
+                }
+            }
+            None => {
+                let _ = fs::remove_file(&staged.target);
+            }
+        }
+        sync_dir(&staged.target);
+    }
+}
+
+pub fn write_files(writes: &[(PathBuf, String)]) -> io::Result<()> {
+    let mut staged = Vec::new();
+    for (path, contents) in writes {
+        match stage(path, contents.as_bytes()) {
+            Ok(write) => staged.push(write),
+            Err(e) => {
+                for write in &staged {
+                    let _ = fs::remove_file(&write.temp);
+                }
+                return Err(with_path(path, e));
+            }
+        }
+    }
+
+    for index in 0..staged.len() {
+        if let Err(e) = fs::rename(&staged[index].temp, &staged[index].target) {
+            for write in &staged[index..] {
+                let _ = fs::remove_file(&write.temp);
+            }
+            roll_back(&staged[..index]);
+            return Err(with_path(&staged[index].target, e));
+        }
+        sync_dir(&staged[index].target);
+    }
+    Ok(())
+}
@@ -0,0 +1,382 @@
+use gtk::{
+    glib, prelude::*, Box, Button, DropDown, Entry, Label, ListBox, Orientation, Popover,
+    ScrolledWindow, SearchEntry, SelectionMode, StringList, Switch,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::widgets::WidgetBuilder;
+
+pub const EXEC_KEYWORDS: [&str; 5] = ["exec-once", "exec", "execr-once", "execr", "exec-shutdown"];
+
+pub fn is_exec_keyword(keyword: &str) -> bool {
+    EXEC_KEYWORDS.contains(&keyword)
+}
+
+pub fn path_executables() -> Vec<String> {
+    let mut executables = BTreeSet::new();
+    let path = env::var("PATH").unwrap_or_default();
+    for dir in env::split_paths(&path) {
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries.flatten() {
+            let is_executable = entry
+                .metadata()
+                .is_ok_and(|m| m.is_file() && m.permissions().mode() & 0o111 != 0);
+            if is_executable {
+                executables.insert(entry.file_name().to_string_lossy().to_string());
+            }
+        }
+    }
+    executables.into_iter().collect()
+}
+
+#[derive(Clone)]
+pub struct AutostartPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    entries: Rc<RefCell<Vec<Directive>>>,
+    files: Rc<RefCell<Vec<String>>>,
+    new_file: DropDown,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl AutostartPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Autostart",
+            "Commands run at startup, on reload or on shutdown. Disabled entries are commented out.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Add Entry",
+            "Pick a command from your PATH or type one in.",
+            first_section.clone(),
+        );
+
+        let add_box = Box::new(Orientation::Horizontal, 5);
+        add_box.set_margin_top(10);
+
+        let new_keyword = DropDown::new(
+            Some(StringList::new(&EXEC_KEYWORDS)),
+            None::<gtk::Expression>,
+        );
+
+        let new_command = Entry::new();
+        new_command.set_placeholder_text(Some("Command"));
+        new_command.set_hexpand(true);
+
+        let browse_button = Button::from_icon_name("system-search-symbolic");
+        browse_button.set_tooltip_text(Some("Pick an executable from PATH"));
+        let browse_popover = build_executable_picker(&new_command);
+        browse_popover.set_parent(&browse_button);
+        browse_button.connect_clicked(move |_| {
+            browse_popover.popup();
+        });
+
+        let new_file = DropDown::new(
+            Some(StringList::new(&["hyprland.conf"])),
+            None::<gtk::Expression>,
+        );
+
+        let add_button = Button::with_label("Add");
+
+        add_box.append(&new_keyword);
+        add_box.append(&new_command);
+        add_box.append(&browse_button);
+        add_box.append(&new_file);
+        add_box.append(&add_button);
+        container.append(&add_box);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            entries: Rc::new(RefCell::new(Vec::new())),
+            files: Rc::new(RefCell::new(Vec::new())),
+            new_file,
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let command = new_command.text().trim().to_string();
+            if command.is_empty() {
+                new_command.add_css_class("error");
+                return;
+            }
+            new_command.remove_css_class("error");
+
+            let keyword = EXEC_KEYWORDS[new_keyword.selected() as usize];
+            let file = page_clone.new_file.selected() as usize;
+            page_clone
+                .entries
+                .borrow_mut()
+                .push(Directive::new(keyword, &command, file, None));
+            new_command.set_text("");
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.entries.borrow_mut() = directives::collect_with_disabled(config, &is_exec_keyword);
+        *self.files.borrow_mut() = directives::file_labels(config);
+
+        let files = self.files.borrow();
+        let labels: Vec<&str> = files.iter().map(String::as_str).collect();
+        self.new_file.set_model(Some(&StringList::new(&labels)));
+        self.new_file.set_selected(0);
+        drop(files);
+
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply_with_disabled(config, &is_exec_keyword, &self.entries.borrow());
+        }
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+
+        let count = self.entries.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut Directive)) {
+        if let Some(directive) = self.entries.borrow_mut().get_mut(index) {
+            edit(directive);
+        }
+        *self.changed.borrow_mut() = true;
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let directive = self.entries.borrow()[index].clone();
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+        hbox.set_margin_start(5);
+        hbox.set_margin_end(5);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let enabled_switch = Switch::new();
+        enabled_switch.set_valign(gtk::Align::Center);
+        enabled_switch.set_active(!directive.disabled);
+        enabled_switch.set_tooltip_text(Some("Disabled entries are commented out"));
+
+        let mut keywords: Vec<&str> = EXEC_KEYWORDS.to_vec();
+        if !keywords.contains(&directive.keyword.as_str()) {
+            keywords.push(&directive.keyword);
+        }
+        let keyword_dropdown =
+            DropDown::new(Some(StringList::new(&keywords)), None::<gtk::Expression>);
+        if let Some(position) = keywords.iter().position(|k| *k == directive.keyword) {
+            keyword_dropdown.set_selected(position as u32);
+        }
+
+        let command_entry = Entry::new();
+        command_entry.set_text(&directive.value);
+        command_entry.set_hexpand(true);
+        command_entry.set_sensitive(!directive.disabled);
+
+        let files = self.files.borrow();
+        let file_labels: Vec<&str> = files.iter().map(String::as_str).collect();
+        let file_dropdown =
+            DropDown::new(Some(StringList::new(&file_labels)), None::<gtk::Expression>);
+        file_dropdown.set_selected(directive.file as u32);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.entries.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&enabled_switch);
+        hbox.append(&keyword_dropdown);
+        hbox.append(&command_entry);
+        hbox.append(&file_dropdown);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        let page = self.clone();
+        let entry_clone = command_entry.clone();
+        enabled_switch.connect_state_set(move |_, state| {
+            entry_clone.set_sensitive(state);
+            page.update(index, |d| d.disabled = !state);
+            glib::Propagation::Proceed
+        });
+
+        let page = self.clone();
+        keyword_dropdown.connect_selected_notify(move |dd| {
+            let keyword = dd
+                .selected_item()
+                .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+                .map(|item| item.string().to_string())
+                .unwrap_or_default();
+            page.update(index, |d| d.keyword = keyword.clone());
+        });
+
+        let page = self.clone();
+        command_entry.connect_changed(move |entry| {
+            let command = entry.text().to_string();
+            page.update(index, |d| d.value = command.clone());
+        });
+
+        let page = self.clone();
+        file_dropdown.connect_selected_notify(move |dd| {
+            let file = dd.selected() as usize;
+            page.update(index, |d| {
+                d.file = file;
+                d.line = None;
+            });
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.entries.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.entries.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.entries.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        hbox
+    }
+}
+
+fn build_executable_picker(command_entry: &Entry) -> Popover {
+    let popover = Popover::new();
+
+    let vbox = Box::new(Orientation::Vertical, 5);
+    vbox.set_margin_start(5);
+    vbox.set_margin_end(5);
+    vbox.set_margin_top(5);
+    vbox.set_margin_bottom(5);
+
+    let search_entry = SearchEntry::new();
+    search_entry.set_placeholder_text(Some("Search executables"));
+
+    let results = ListBox::new();
+    results.set_selection_mode(SelectionMode::None);
+    results.set_activate_on_single_click(true);
+
+    let scrolled = ScrolledWindow::new();
+    scrolled.set_min_content_height(300);
+    scrolled.set_min_content_width(260);
+    scrolled.set_child(Some(&results));
+
+    vbox.append(&search_entry);
+    vbox.append(&scrolled);
+    popover.set_child(Some(&vbox));
+
+    let executables: Rc<RefCell<Option<Vec<String>>>> = Rc::new(RefCell::new(None));
+
+    let fill_results = {
+        let results = results.clone();
+        let executables = executables.clone();
+        move |query: &str| {
+            while let Some(row) = results.first_child() {
+                results.remove(&row);
+            }
+            let mut executables = executables.borrow_mut();
+            let executables = executables.get_or_insert_with(path_executables);
+            let query = query.to_lowercase();
+            for name in executables
+                .iter()
+                .filter(|name| name.to_lowercase().contains(&query))
+                .take(200)
+            {
+                let label = Label::new(Some(name));
+                label.set_xalign(0.0);
+                results.append(&label);
+            }
+        }
+    };
+
+    let fill = fill_results.clone();
+    popover.connect_show(move |_| {
+        fill("");
+    });
+
+    search_entry.connect_search_changed(move |entry| {
+        fill_results(&entry.text());
+    });
+
+    let command_entry = command_entry.clone();
+    let popover_clone = popover.clone();
+    results.connect_row_activated(move |_, row| {
+        if let Some(name) = row.child().and_downcast::<Label>().map(|l| l.text()) {
+            let current = command_entry.text().to_string();
+            let arguments = current
+                .split_once(char::is_whitespace)
+                .map(|(_, rest)| rest)
+                .unwrap_or_default();
+            if arguments.is_empty() {
+                command_entry.set_text(&name);
+            } else {
+                command_entry.set_text(&format!("{} {}", name, arguments));
+            }
+        }
+        popover_clone.popdown();
+    });
+
+    popover
+}
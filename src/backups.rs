@@ -0,0 +1,267 @@
+use gtk::{
+    glib, prelude::*, Box, Button, Label, ListBox, Orientation, Paned, ScrolledWindow,
+    SelectionMode,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::{env, fs, io, path::Path, path::PathBuf};
+
+use crate::{atomic, diff};
+
+const BACKUP_DIR: &str = ".local/state/hyprgui/backups";
+const MANIFEST_FILE: &str = "manifest";
+const MAX_SNAPSHOTS: usize = 20;
+
+#[derive(Clone)]
+pub struct Snapshot {
+    pub dir: PathBuf,
+    pub name: String,
+    pub files: Vec<(PathBuf, PathBuf)>,
+}
+
+impl Snapshot {
+    pub fn title(&self) -> String {
+        match self.name.split_once('_') {
+            Some((date, time)) => format!("{} {}", date, time.replacen('-', ":", 2)),
+            None => self.name.clone(),
+        }
+    }
+}
+
+pub fn backup_root() -> PathBuf {
+    match env::var("XDG_STATE_HOME") {
+        Ok(state) if !state.is_empty() => Path::new(&state).join("hyprgui/backups"),
+        _ => Path::new(&env::var("HOME").unwrap_or_else(|_| ".".to_string())).join(BACKUP_DIR),
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    let path = path.to_string_lossy().to_string();
+    let home = env::var("HOME").unwrap_or_default();
+    if !home.is_empty() && path.starts_with(&home) {
+        format!("~{}", &path[home.len()..])
+    } else {
+        path
+    }
+}
+
+fn timestamp() -> String {
+    glib::DateTime::now_local()
+        .and_then(|now| now.format("%Y-%m-%d_%H-%M-%S"))
+        .map(|s| s.to_string())
+        .unwrap_or_else(|_| "snapshot".to_string())
+}
+
+pub fn create_snapshot(paths: &[PathBuf], protect: Option<&Path>) -> io::Result<Snapshot> {
+    let root = backup_root();
+    let timestamp = timestamp();
+    let mut name = timestamp.clone();
+    let mut suffix = 1;
+    while root.join(&name).exists() {
+        name = format!("{}.{}", timestamp, suffix);
+        suffix += 1;
+    }
+    let dir = root.join(&name);
+    fs::create_dir_all(&dir)?;
+
+    let mut files = Vec::new();
+    let mut manifest = String::new();
+    for (index, path) in paths.iter().enumerate() {
+        if !path.exists() {
+            continue;
+        }
+        let stored = dir.join(index.to_string());
+        fs::copy(path, &stored)?;
+        manifest.push_str(&format!("{}\t{}\n", index, path.display()));
+        files.push((path.clone(), stored));
+    }
+    fs::write(dir.join(MANIFEST_FILE), manifest)?;
+
+    prune(MAX_SNAPSHOTS, protect)?;
+
+    Ok(Snapshot { name, dir, files })
+}
+
+fn read_snapshot(dir: &Path) -> Option<Snapshot> {
+    let manifest = fs::read_to_string(dir.join(MANIFEST_FILE)).ok()?;
+    let files = manifest
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(stored, path)| (PathBuf::from(path), dir.join(stored)))
+        .collect();
+    Some(Snapshot {
+        dir: dir.to_path_buf(),
+        name: dir.file_name()?.to_string_lossy().to_string(),
+        files,
+    })
+}
+
+pub fn list_snapshots() -> Vec<Snapshot> {
+    let mut snapshots: Vec<Snapshot> = match fs::read_dir(backup_root()) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| read_snapshot(&entry.path()))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    snapshots.sort_by(|a, b| b.name.cmp(&a.name));
+    snapshots
+}
+
+fn prune(keep: usize, protect: Option<&Path>) -> io::Result<()> {
+    for snapshot in list_snapshots()
+        .iter()
+        .filter(|snapshot| Some(snapshot.dir.as_path()) != protect)
+        .skip(keep)
+    {
+        fs::remove_dir_all(&snapshot.dir)?;
+    }
+    Ok(())
+}
+
+pub fn restore(snapshot: &Snapshot) -> io::Result<()> {
+    let mut writes = Vec::new();
+    for (path, stored) in &snapshot.files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        writes.push((path.clone(), fs::read_to_string(stored)?));
+    }
+    atomic::write_files(&writes)
+}
+
+fn snapshot_markup(snapshot: &Snapshot) -> String {
+    let mut sections = Vec::new();
+    for (path, stored) in &snapshot.files {
+        let current: Vec<String> = fs::read_to_string(path)
+            .unwrap_or_default()
+            .lines()
+            .map(String::from)
+            .collect();
+        let saved: Vec<String> = fs::read_to_string(stored)
+            .unwrap_or_default()
+            .lines()
+            .map(String::from)
+            .collect();
+        let hunks = diff::diff_hunks(&current, &saved);
+        if hunks.is_empty() {
+            continue;
+        }
+        let mut section = format!("<b>{}</b>", glib::markup_escape_text(&display_path(path)));
+        for hunk in &hunks {
+            section.push('\n');
+            section.push_str(&diff::hunk_markup(hunk));
+        }
+        sections.push(section);
+    }
+    if sections.is_empty() {
+        "This snapshot matches the current configuration.".to_string()
+    } else {
+        sections.join("\n\n")
+    }
+}
+
+pub fn show_backup_browser(
+    parent: &impl IsA<gtk::Window>,
+    on_restore: impl Fn(&Snapshot) + 'static,
+) {
+    let window = gtk::Window::builder()
+        .title("Backup History")
+        .modal(true)
+        .transient_for(parent)
+        .default_width(900)
+        .default_height(600)
+        .build();
+
+    let snapshots = list_snapshots();
+
+    let vbox = Box::new(Orientation::Vertical, 10);
+    vbox.set_margin_start(10);
+    vbox.set_margin_end(10);
+    vbox.set_margin_top(10);
+    vbox.set_margin_bottom(10);
+
+    let list = ListBox::new();
+    list.set_selection_mode(SelectionMode::Single);
+    for snapshot in &snapshots {
+        let label = Label::new(Some(&format!(
+            "{}\n{} file{}",
+            snapshot.title(),
+            snapshot.files.len(),
+            if snapshot.files.len() == 1 { "" } else { "s" }
+        )));
+        label.set_halign(gtk::Align::Start);
+        label.set_margin_start(5);
+        label.set_margin_end(5);
+        label.set_margin_top(5);
+        label.set_margin_bottom(5);
+        list.append(&label);
+    }
+
+    let list_window = ScrolledWindow::new();
+    list_window.set_child(Some(&list));
+    list_window.set_width_request(220);
+
+    let diff_label = Label::new(Some(if snapshots.is_empty() {
+        "No backups yet. A snapshot is taken every time the configuration is saved."
+    } else {
+        "Select a snapshot to compare it with the current configuration."
+    }));
+    diff_label.set_halign(gtk::Align::Start);
+    diff_label.set_valign(gtk::Align::Start);
+    diff_label.set_selectable(true);
+    diff_label.add_css_class("monospace");
+
+    let diff_window = ScrolledWindow::new();
+    diff_window.set_child(Some(&diff_label));
+    diff_window.set_hexpand(true);
+
+    let paned = Paned::new(Orientation::Horizontal);
+    paned.set_start_child(Some(&list_window));
+    paned.set_end_child(Some(&diff_window));
+    paned.set_vexpand(true);
+
+    let button_box = Box::new(Orientation::Horizontal, 5);
+    button_box.set_halign(gtk::Align::End);
+    let close_button = Button::with_label("Close");
+    let restore_button = Button::with_label("Restore Snapshot");
+    restore_button.add_css_class("suggested-action");
+    restore_button.set_sensitive(false);
+    button_box.append(&close_button);
+    button_box.append(&restore_button);
+
+    vbox.append(&paned);
+    vbox.append(&button_box);
+    window.set_child(Some(&vbox));
+
+    let snapshots = Rc::new(snapshots);
+    let selected = Rc::new(RefCell::new(None::<usize>));
+
+    let snapshots_clone = snapshots.clone();
+    let selected_clone = selected.clone();
+    let restore_button_clone = restore_button.clone();
+    list.connect_row_selected(move |_, row| {
+        let index = row.map(|row| row.index() as usize);
+        *selected_clone.borrow_mut() = index;
+        restore_button_clone.set_sensitive(index.is_some());
+        if let Some(snapshot) = index.and_then(|index| snapshots_clone.get(index)) {
+            diff_label.set_markup(&snapshot_markup(snapshot));
+        }
+    });
+
+    let window_clone = window.clone();
+    close_button.connect_clicked(move |_| {
+        window_clone.close();
+    });
+
+    let window_clone = window.clone();
+    restore_button.connect_clicked(move |_| {
+        if let Some(snapshot) = selected.borrow().and_then(|index| snapshots.get(index)) {
+            window_clone.close();
+            on_restore(snapshot);
+        }
+    });
+
+    window.present();
+}
@@ -0,0 +1,442 @@
+pub const BIND_KEYWORDS: [&str; 9] = [
+    "bind", "bindm", "binde", "bindl", "bindr", "bindn", "bindel", "bindo", "bindd",
+];
+
+const BIND_FLAGS: &str = "lrotenmisdpcgu";
+
+pub fn is_bind_keyword(keyword: &str) -> bool {
+    keyword
+        .strip_prefix("bind")
+        .is_some_and(|flags| flags.chars().all(|c| BIND_FLAGS.contains(c)))
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Bind {
+    pub flags: String,
+    pub mods: String,
+    pub key: String,
+    pub description: String,
+    pub dispatcher: String,
+    pub args: String,
+}
+
+impl Bind {
+    pub fn parse(keyword: &str, value: &str) -> Option<Self> {
+        let flags = keyword.strip_prefix("bind")?.to_string();
+        let has_description = flags.contains('d');
+        let count = if has_description { 5 } else { 4 };
+        let mut parts = value.splitn(count, ',').map(str::trim);
+
+        let mods = parts.next().unwrap_or_default().to_string();
+        let key = parts.next().unwrap_or_default().to_string();
+        let description = if has_description {
+            parts.next().unwrap_or_default().to_string()
+        } else {
+            String::new()
+        };
+        let dispatcher = parts.next().unwrap_or_default().to_string();
+        let args = parts.next().unwrap_or_default().to_string();
+
+        Some(Self {
+            flags,
+            mods,
+            key,
+            description,
+            dispatcher,
+            args,
+        })
+    }
+
+    pub fn keyword(&self) -> String {
+        format!("bind{}", self.flags)
+    }
+
+    pub fn value(&self) -> String {
+        let mut parts = vec![self.mods.as_str(), self.key.as_str()];
+        if self.flags.contains('d') {
+            parts.push(self.description.as_str());
+        }
+        parts.push(self.dispatcher.as_str());
+        if !self.args.is_empty() {
+            parts.push(self.args.as_str());
+        }
+        parts.join(", ")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConflictKind {
+    Duplicate,
+    FlagsOnly,
+    Collision,
+}
+
+impl ConflictKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConflictKind::Duplicate => "Exact duplicate",
+            ConflictKind::FlagsOnly => "Differs only in flags",
+            ConflictKind::Collision => "Same combo, different action",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Conflict {
+    pub kind: ConflictKind,
+    pub first: usize,
+    pub second: usize,
+}
+
+pub fn normalize_mods(mods: &str) -> Vec<String> {
+    let known = [
+        ("SUPER", &["SUPER", "WIN", "LOGO", "MOD4"][..]),
+        ("CTRL", &["CTRL", "CONTROL"][..]),
+        ("ALT", &["ALT"][..]),
+        ("SHIFT", &["SHIFT"][..]),
+        ("CAPS", &["CAPS"][..]),
+        ("MOD2", &["MOD2"][..]),
+        ("MOD3", &["MOD3"][..]),
+        ("MOD5", &["MOD5"][..]),
+    ];
+
+    let tokens: Vec<&str> = mods
+        .split(|c: char| c.is_whitespace() || c == '_' || c == '+')
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    let mut found: Vec<&str> = Vec::new();
+    for token in tokens.iter().filter(|token| !token.starts_with('$')) {
+        let upper = token.to_uppercase();
+        let mut rest = upper.as_str();
+        let mut names = Vec::new();
+        while !rest.is_empty() {
+            let matched = known.iter().find_map(|(name, aliases)| {
+                aliases
+                    .iter()
+                    .find_map(|alias| rest.strip_prefix(alias))
+                    .map(|remaining| (*name, remaining))
+            });
+            match matched {
+                Some((name, remaining)) => {
+                    names.push(name);
+                    rest = remaining;
+                }
+                None => {
+                    names.clear();
+                    break;
+                }
+            }
+        }
+        found.extend(names);
+    }
+
+    let mut normalized: Vec<String> = known
+        .iter()
+        .filter(|(name, _)| found.contains(name))
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    let mut variables: Vec<String> = tokens
+        .iter()
+        .filter(|token| token.starts_with('$'))
+        .map(|token| token.to_string())
+        .collect();
+    variables.sort();
+    variables.dedup();
+
+    normalized.extend(variables);
+    normalized
+}
+
+pub fn combo_label(bind: &Bind) -> String {
+    let mut parts = normalize_mods(&bind.mods);
+    parts.push(bind.key.clone());
+    parts.join(" + ")
+}
+
+pub fn find_conflicts(binds: &[(Option<String>, Bind)]) -> Vec<Conflict> {
+    let keys: Vec<(Vec<String>, String)> = binds
+        .iter()
+        .map(|(_, bind)| (normalize_mods(&bind.mods), bind.key.to_lowercase()))
+        .collect();
+
+    let mut conflicts = Vec::new();
+    for first in 0..binds.len() {
+        for second in first + 1..binds.len() {
+            let (first_submap, first_bind) = &binds[first];
+            let (second_submap, second_bind) = &binds[second];
+
+            if first_submap != second_submap
+                || keys[first] != keys[second]
+                || first_bind.key.is_empty()
+            {
+                continue;
+            }
+
+            let same_action = first_bind.dispatcher == second_bind.dispatcher
+                && first_bind.args == second_bind.args;
+            let kind = if same_action && first_bind.flags == second_bind.flags {
+                ConflictKind::Duplicate
+            } else if same_action {
+                ConflictKind::FlagsOnly
+            } else {
+                ConflictKind::Collision
+            };
+
+            conflicts.push(Conflict {
+                kind,
+                first,
+                second,
+            });
+        }
+    }
+    conflicts
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CheatSheetFormat {
+    Markdown,
+    Html,
+    Text,
+}
+
+struct CheatSheetGroup<'a> {
+    submap: Option<&'a str>,
+    dispatcher: &'a str,
+    rows: Vec<[String; 3]>,
+}
+
+fn cheatsheet_groups(binds: &[(Option<String>, Bind)]) -> Vec<CheatSheetGroup<'_>> {
+    let mut submaps: Vec<Option<&str>> = Vec::new();
+    for (submap, _) in binds {
+        if !submaps.contains(&submap.as_deref()) {
+            submaps.push(submap.as_deref());
+        }
+    }
+
+    let mut groups = Vec::new();
+    for submap in submaps {
+        let mut dispatchers: Vec<&str> = Vec::new();
+        for (_, bind) in binds.iter().filter(|(s, _)| s.as_deref() == submap) {
+            if !dispatchers.contains(&bind.dispatcher.as_str()) {
+                dispatchers.push(&bind.dispatcher);
+            }
+        }
+
+        for dispatcher in dispatchers {
+            let rows = binds
+                .iter()
+                .filter(|(s, bind)| s.as_deref() == submap && bind.dispatcher == dispatcher)
+                .map(|(_, bind)| {
+                    [
+                        combo_label(bind),
+                        format!("{} {}", bind.dispatcher, bind.args)
+                            .trim()
+                            .to_string(),
+                        bind.description.clone(),
+                    ]
+                })
+                .collect();
+            groups.push(CheatSheetGroup {
+                submap,
+                dispatcher,
+                rows,
+            });
+        }
+    }
+    groups
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn render_cheatsheet(binds: &[(Option<String>, Bind)], format: CheatSheetFormat) -> String {
+    let groups = cheatsheet_groups(binds);
+    let headers = ["Keys", "Action", "Description"];
+    let mut out = String::new();
+    let mut current_submap = None;
+
+    match format {
+        CheatSheetFormat::Markdown => out.push_str("# Hyprland Keybindings\n"),
+        CheatSheetFormat::Html => out.push_str(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Hyprland Keybindings</title>\n<style>\n\
+             body { font-family: sans-serif; margin: 2em; }\n\
+             table { border-collapse: collapse; margin-bottom: 1em; }\n\
+             th, td { border: 1px solid #888; padding: 4px 8px; text-align: left; }\n\
+             td:first-child { font-family: monospace; white-space: nowrap; }\n\
+             </style>\n</head>\n<body>\n<h1>Hyprland Keybindings</h1>\n",
+        ),
+        CheatSheetFormat::Text => out.push_str("HYPRLAND KEYBINDINGS\n"),
+    }
+
+    for (i, group) in groups.iter().enumerate() {
+        if i == 0 || group.submap != current_submap {
+            current_submap = group.submap;
+            let title = match group.submap {
+                Some(submap) => format!("Submap: {}", submap),
+                None => "Global".to_string(),
+            };
+            match format {
+                CheatSheetFormat::Markdown => out.push_str(&format!("\n## {}\n", title)),
+                CheatSheetFormat::Html => {
+                    out.push_str(&format!("<h2>{}</h2>\n", escape_html(&title)))
+                }
+                CheatSheetFormat::Text => out.push_str(&format!(
+                    "\n{}\n{}\n",
+                    title,
+                    "=".repeat(title.chars().count())
+                )),
+            }
+        }
+
+        match format {
+            CheatSheetFormat::Markdown => {
+                out.push_str(&format!("\n### {}\n\n", group.dispatcher));
+                out.push_str(&format!("| {} |\n|---|---|---|\n", headers.join(" | ")));
+                for row in &group.rows {
+                    let cells: Vec<String> = row.iter().map(|c| c.replace('|', "\\|")).collect();
+                    out.push_str(&format!("| {} |\n", cells.join(" | ")));
+                }
+            }
+            CheatSheetFormat::Html => {
+                out.push_str(&format!(
+                    "<h3>{}</h3>\n<table>\n<tr>",
+                    escape_html(group.dispatcher)
+                ));
+                for header in headers {
+                    out.push_str(&format!("<th>{}</th>", header));
+                }
+                out.push_str("</tr>\n");
+                for row in &group.rows {
+                    out.push_str("<tr>");
+                    for cell in row {
+                        out.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</table>\n");
+            }
+            CheatSheetFormat::Text => {
+                let mut widths = headers.map(|h| h.chars().count());
+                for row in &group.rows {
+                    for (width, cell) in widths.iter_mut().zip(row.iter()) {
+                        *width = (*width).max(cell.chars().count());
+                    }
+                }
+                let format_row = |cells: [&str; 3]| {
+                    format!(
+                        "  {:<w0$}  {:<w1$}  {}",
+                        cells[0],
+                        cells[1],
+                        cells[2],
+                        w0 = widths[0],
+                        w1 = widths[1]
+                    )
+                    .trim_end()
+                    .to_string()
+                };
+                out.push_str(&format!("\n{}\n", group.dispatcher));
+                out.push_str(&format!("{}\n", format_row(headers)));
+                out.push_str(&format!(
+                    "  {}  {}  {}\n",
+                    "-".repeat(widths[0]),
+                    "-".repeat(widths[1]),
+                    "-".repeat(widths[2])
+                ));
+                for row in &group.rows {
+                    out.push_str(&format!(
+                        "{}\n",
+                        format_row([row[0].as_str(), row[1].as_str(), row[2].as_str()])
+                    ));
+                }
+            }
+        }
+    }
+
+    if format == CheatSheetFormat::Html {
+        out.push_str("</body>\n</html>\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bind(keyword: &str, value: &str) -> (Option<String>, Bind) {
+        (None, Bind::parse(keyword, value).unwrap())
+    }
+
+    #[test]
+    fn parse_round_trips_binds() {
+        let parsed = Bind::parse("bind", "SUPER, Q, exec, kitty --single-instance").unwrap();
+        assert_eq!(parsed.mods, "SUPER");
+        assert_eq!(parsed.key, "Q");
+        assert_eq!(parsed.dispatcher, "exec");
+        assert_eq!(parsed.args, "kitty --single-instance");
+        assert_eq!(parsed.value(), "SUPER, Q, exec, kitty --single-instance");
+
+        let described = Bind::parse("bindd", "SUPER, C, Close window, killactive,").unwrap();
+        assert_eq!(described.description, "Close window");
+        assert_eq!(described.dispatcher, "killactive");
+        assert_eq!(described.keyword(), "bindd");
+        assert_eq!(described.value(), "SUPER, C, Close window, killactive");
+    }
+
+    #[test]
+    fn parse_rejects_other_keywords() {
+        assert!(Bind::parse("exec", "kitty").is_none());
+    }
+
+    #[test]
+    fn conflicts_are_classified() {
+        let binds = vec![
+            bind("bind", "SUPER, Q, killactive,"),
+            bind("bind", "SUPER, q, killactive,"),
+            bind("binde", "SUPER, Q, killactive,"),
+            bind("bind", "SUPER, Q, exec, kitty"),
+        ];
+        let kinds: Vec<(ConflictKind, usize, usize)> = find_conflicts(&binds)
+            .into_iter()
+            .map(|c| (c.kind, c.first, c.second))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ConflictKind::Duplicate, 0, 1),
+                (ConflictKind::FlagsOnly, 0, 2),
+                (ConflictKind::Collision, 0, 3),
+                (ConflictKind::FlagsOnly, 1, 2),
+                (ConflictKind::Collision, 1, 3),
+                (ConflictKind::Collision, 2, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicts_normalize_modifier_spelling() {
+        let binds = vec![
+            bind("bind", "SUPER_SHIFT, 1, movetoworkspace, 1"),
+            bind("bind", "shift win, 1, workspace, 1"),
+        ];
+        assert_eq!(find_conflicts(&binds).len(), 1);
+    }
+
+    #[test]
+    fn different_submaps_and_empty_keys_do_not_conflict() {
+        let mut binds = vec![
+            bind("bind", "SUPER, R, submap, resize"),
+            bind("bind", "SUPER, R, exec, rofi"),
+            bind("bind", ", , exec, a"),
+            bind("bind", ", , exec, b"),
+        ];
+        binds[1].0 = Some("resize".to_string());
+        assert!(find_conflicts(&binds).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+use regex::Regex;
+use serde_json::Value;
+use std::env;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::rules::WindowRule;
+
+const SOCKET_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Debug, Default)]
+pub struct Client {
+    pub class: String,
+    pub title: String,
+    pub initial_class: String,
+    pub initial_title: String,
+    pub xwayland: bool,
+    pub floating: bool,
+    pub fullscreen: bool,
+    pub pinned: bool,
+    pub focused: bool,
+    pub grouped: bool,
+    pub workspace_id: i64,
+    pub workspace_name: String,
+    pub tags: Vec<String>,
+}
+
+impl Client {
+    fn from_json(value: &Value) -> Self {
+        let string = |key: &str| value[key].as_str().unwrap_or_default().to_string();
+        let flag = |key: &str| match &value[key] {
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.as_i64().unwrap_or(0) != 0,
+            _ => false,
+        };
+
+        Self {
+            class: string("class"),
+            title: string("title"),
+            initial_class: string("initialClass"),
+            initial_title: string("initialTitle"),
+            xwayland: flag("xwayland"),
+            floating: flag("floating"),
+            fullscreen: flag("fullscreen"),
+            pinned: flag("pinned"),
+            focused: value["focusHistoryID"].as_i64() == Some(0),
+            grouped: value["grouped"].as_array().is_some_and(|g| !g.is_empty()),
+            workspace_id: value["workspace"]["id"].as_i64().unwrap_or_default(),
+            workspace_name: value["workspace"]["name"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            tags: value["tags"]
+                .as_array()
+                .map(|tags| {
+                    tags.iter()
+                        .filter_map(Value::as_str)
+                        .map(|tag| tag.trim_end_matches('*').to_string())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        format!("{} \u{2014} {}", self.class, self.title)
+    }
+}
+
+pub fn parse_clients(json: &str) -> Result<Vec<Client>, String> {
+    let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let clients = value
+        .as_array()
+        .ok_or_else(|| "Expected a JSON array of clients".to_string())?;
+    Ok(clients.iter().map(Client::from_json).collect())
+}
+
+fn socket_path() -> Result<PathBuf, String> {
+    let signature = env::var("HYPRLAND_INSTANCE_SIGNATURE")
+        .map_err(|_| "Hyprland is not running (HYPRLAND_INSTANCE_SIGNATURE is unset)")?;
+    let runtime_path = env::var("XDG_RUNTIME_DIR")
+        .map(|dir| PathBuf::from(dir).join("hypr").join(&signature))
+        .unwrap_or_default();
+    let path = if runtime_path.exists() {
+        runtime_path
+    } else {
+        PathBuf::from("/tmp/hypr").join(&signature)
+    };
+    Ok(path.join(".socket.sock"))
+}
+
+pub fn fetch_clients() -> Result<String, String> {
+    let path = socket_path()?;
+    let mut stream = UnixStream::connect(&path)
+        .map_err(|e| format!("Failed to connect to {}: {}", path.display(), e))?;
+    stream
+        .set_read_timeout(Some(SOCKET_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(SOCKET_TIMEOUT)))
+        .map_err(|e| format!("Failed to configure the socket: {}", e))?;
+    stream
+        .write_all(b"j/clients")
+        .map_err(|e| format!("Failed to query clients: {}", e))?;
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| format!("Failed to read clients: {}", e))?;
+    Ok(response)
+}
+
+fn regex_matches(pattern: &str, text: &str) -> Result<bool, String> {
+    let (negative, pattern) = match pattern.strip_prefix("negative:") {
+        Some(pattern) => (true, pattern),
+        None => (false, pattern),
+    };
+    Regex::new(pattern).map_err(|e| format!("Invalid regex '{}': {}", pattern, e))?;
+    let regex = Regex::new(&format!("^(?:{})$", pattern)).map_err(|e| e.to_string())?;
+    Ok(regex.is_match(text) != negative)
+}
+
+fn parse_flag(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "1" | "true" | "yes" | "on"
+    )
+}
+
+pub fn is_testable_matcher(matcher: &str) -> bool {
+    matches!(
+        matcher,
+        "class"
+            | "title"
+            | "initialClass"
+            | "initialTitle"
+            | "tag"
+            | "xwayland"
+            | "floating"
+            | "fullscreen"
+            | "pinned"
+            | "focus"
+            | "group"
+            | "workspace"
+    )
+}
+
+pub fn rule_matches(rule: &WindowRule, client: &Client) -> Result<bool, String> {
+    for (matcher, value) in &rule.matchers {
+        let value = value.trim();
+        let matched = match matcher.as_str() {
+            "class" => regex_matches(value, &client.class)?,
+            "title" => regex_matches(value, &client.title)?,
+            "initialClass" => regex_matches(value, &client.initial_class)?,
+            "initialTitle" => regex_matches(value, &client.initial_title)?,
+            "tag" => client.tags.iter().any(|tag| tag == value),
+            "xwayland" => client.xwayland == parse_flag(value),
+            "floating" => client.floating == parse_flag(value),
+            "fullscreen" => client.fullscreen == parse_flag(value),
+            "pinned" => client.pinned == parse_flag(value),
+            "focus" => client.focused == parse_flag(value),
+            "group" => client.grouped == parse_flag(value),
+            "workspace" => match value.strip_prefix("name:") {
+                Some(name) => client.workspace_name == name,
+                None => value.parse::<i64>().ok() == Some(client.workspace_id),
+            },
+            _ => true,
+        };
+        if !matched {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
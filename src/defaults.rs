@@ -0,0 +1,275 @@
+use hyprparser::HyprlandConfig;
+
+pub fn option_default(category: &str, name: &str) -> Option<&'static str> {
+    let default = match (category, name) {
+        ("general", "layout") => "dwindle",
+        ("general", "gaps_in") => "5",
+        ("general", "gaps_out") => "20",
+        ("general", "gaps_workspaces") => "0",
+        ("general", "border_size") => "1",
+        ("general", "no_border_on_floating") => "false",
+        ("general", "resize_on_border") => "false",
+        ("general", "extend_border_grab_area") => "15",
+        ("general", "hover_icon_on_border") => "true",
+        ("general", "col.inactive_border") => "0xff444444",
+        ("general", "col.active_border") => "0xffffffff",
+        ("general", "col.nogroup_border") => "0xffffaaff",
+        ("general", "col.nogroup_border_active") => "0xffff00ff",
+
+        ("decoration", "rounding") => "0",
+        ("decoration", "active_opacity") => "1.0",
+        ("decoration", "inactive_opacity") => "1.0",
+        ("decoration", "fullscreen_opacity") => "1.0",
+        ("decoration", "shadow:enabled") => "true",
+        ("decoration", "shadow:range") => "4",
+        ("decoration", "shadow:render_power") => "3",
+        ("decoration", "shadow:sharp") => "false",
+        ("decoration", "shadow:ignore_window") => "true",
+        ("decoration", "shadow:color") => "0xee1a1a1a",
+        ("decoration", "shadow:offset") => "0, 0",
+        ("decoration", "shadow:scale") => "1.0",
+        ("decoration", "dim_inactive") => "false",
+        ("decoration", "dim_strength") => "0.5",
+        ("decoration", "dim_special") => "0.2",
+        ("decoration", "dim_around") => "0.4",
+        ("decoration", "blur:enabled") => "true",
+        ("decoration", "blur:size") => "8",
+        ("decoration", "blur:passes") => "1",
+        ("decoration", "blur:ignore_opacity") => "true",
+        ("decoration", "blur:new_optimizations") => "true",
+        ("decoration", "blur:xray") => "false",
+        ("decoration", "blur:noise") => "0.0117",
+        ("decoration", "blur:contrast") => "0.8916",
+        ("decoration", "blur:brightness") => "0.8172",
+        ("decoration", "blur:vibrancy") => "0.1696",
+        ("decoration", "blur:vibrancy_darkness") => "0.0",
+        ("decoration", "blur:special") => "false",
+        ("decoration", "blur:popups") => "false",
+        ("decoration", "blur:popups_ignorealpha") => "0.2",
+
+        ("animations", "enabled") => "true",
+        ("animations", "first_launch_animation") => "true",
+
+        ("input", "kb_layout") => "us",
+        ("input", "numlock_by_default") => "false",
+        ("input", "resolve_binds_by_sym") => "false",
+        ("input", "repeat_rate") => "25",
+        ("input", "repeat_delay") => "600",
+        ("input", "sensitivity") => "0.0",
+        ("input", "force_no_accel") => "false",
+        ("input", "left_handed") => "false",
+        ("input", "scroll_button") => "0",
+        ("input", "scroll_button_lock") => "false",
+        ("input", "scroll_factor") => "1.0",
+        ("input", "natural_scroll") => "false",
+        ("input", "follow_mouse") => "1",
+        ("input", "mouse_refocus") => "true",
+        ("input", "focus_on_close") => "0",
+        ("input", "float_switch_override_focus") => "1",
+        ("input", "special_fallthrough") => "false",
+        ("input", "touchpad:disable_while_typing") => "true",
+        ("input", "touchpad:natural_scroll") => "false",
+        ("input", "touchpad:scroll_factor") => "1.0",
+        ("input", "touchpad:middle_button_emulation") => "false",
+        ("input", "touchpad:clickfinger_behavior") => "false",
+        ("input", "touchpad:tap-to-click") => "true",
+        ("input", "touchpad:drag_lock") => "false",
+        ("input", "touchpad:tap-and-drag") => "false",
+        ("input", "touchdevice:transform") => "0",
+        ("input", "touchdevice:output") => "[[Auto]]",
+        ("input", "touchdevice:enabled") => "true",
+        ("input", "tablet:transform") => "0",
+        ("input", "tablet:region_position") => "0 0",
+        ("input", "tablet:region_size") => "0 0",
+        ("input", "tablet:relative_input") => "false",
+        ("input", "tablet:left_handed") => "false",
+        ("input", "tablet:active_area_size") => "0 0",
+        ("input", "tablet:active_area_position") => "0 0",
+        ("input", "off_window_axis_events") => "1",
+        ("input", "emulate_discrete_scroll") => "1",
+
+        ("gestures", "workspace_swipe") => "false",
+        ("gestures", "workspace_swipe_fingers") => "3",
+        ("gestures", "workspace_swipe_min_fingers") => "false",
+        ("gestures", "workspace_swipe_distance") => "300",
+        ("gestures", "workspace_swipe_touch") => "false",
+        ("gestures", "workspace_swipe_invert") => "true",
+        ("gestures", "workspace_swipe_touch_invert") => "false",
+        ("gestures", "workspace_swipe_min_speed_to_force") => "30",
+        ("gestures", "workspace_swipe_cancel_ratio") => "0.5",
+        ("gestures", "workspace_swipe_create_new") => "true",
+        ("gestures", "workspace_swipe_direction_lock") => "true",
+        ("gestures", "workspace_swipe_direction_lock_threshold") => "10",
+        ("gestures", "workspace_swipe_forever") => "false",
+        ("gestures", "workspace_swipe_use_r") => "false",
+
+        ("group", "auto_group") => "true",
+        ("group", "insert_after_current") => "true",
+        ("group", "focus_removed_window") => "true",
+        ("group", "drag_into_group") => "1",
+        ("group", "merge_groups_on_drag") => "true",
+        ("group", "merge_groups_on_groupbar") => "true",
+        ("group", "merge_floated_into_tiled_on_groupbar") => "false",
+        ("group", "group_on_movetoworkspace") => "false",
+        ("group", "col.border_active") => "0x66ffff00",
+        ("group", "col.border_inactive") => "0x66777700",
+        ("group", "col.border_locked_active") => "0x66ff5500",
+        ("group", "col.border_locked_inactive") => "0x66775500",
+        ("group", "groupbar:enabled") => "true",
+        ("group", "groupbar:font_size") => "8",
+        ("group", "groupbar:gradients") => "true",
+        ("group", "groupbar:height") => "14",
+        ("group", "groupbar:stacked") => "false",
+        ("group", "groupbar:priority") => "3",
+        ("group", "groupbar:render_titles") => "true",
+        ("group", "groupbar:scrolling") => "true",
+        ("group", "groupbar:text_color") => "0xffffffff",
+        ("group", "groupbar:col.active") => "0x66ffff00",
+        ("group", "groupbar:col.inactive") => "0x66777700",
+        ("group", "groupbar:col.locked_active") => "0x66ff5500",
+        ("group", "groupbar:col.locked_inactive") => "0x66775500",
+
+        ("misc", "disable_hyprland_logo") => "false",
+        ("misc", "disable_splash_rendering") => "false",
+        ("misc", "col.splash") => "0xffffffff",
+        ("misc", "font_family") => "Sans",
+        ("misc", "force_default_wallpaper") => "-1",
+        ("misc", "vfr") => "true",
+        ("misc", "vrr") => "0",
+        ("misc", "mouse_move_enables_dpms") => "false",
+        ("misc", "key_press_enables_dpms") => "false",
+        ("misc", "always_follow_on_dnd") => "true",
+        ("misc", "layers_hog_keyboard_focus") => "true",
+        ("misc", "animate_manual_resizes") => "false",
+        ("misc", "animate_mouse_windowdragging") => "false",
+        ("misc", "disable_autoreload") => "false",
+        ("misc", "enable_swallow") => "false",
+        ("misc", "focus_on_activate") => "false",
+        ("misc", "mouse_move_focuses_monitor") => "true",
+        ("misc", "render_ahead_of_time") => "false",
+        ("misc", "render_ahead_safezone") => "1",
+        ("misc", "allow_session_lock_restore") => "false",
+        ("misc", "background_color") => "0xff111111",
+        ("misc", "close_special_on_empty") => "true",
+        ("misc", "new_window_takes_over_fullscreen") => "0",
+        ("misc", "exit_window_retains_fullscreen") => "false",
+        ("misc", "initial_workspace_tracking") => "1",
+        ("misc", "middle_click_paste") => "true",
+        ("misc", "render_unfocused_fps") => "15",
+        ("misc", "disable_xdg_env_checks") => "false",
+
+        ("binds", "pass_mouse_when_bound") => "false",
+        ("binds", "scroll_event_delay") => "300",
+        ("binds", "workspace_back_and_forth") => "false",
+        ("binds", "allow_workspace_cycles") => "false",
+        ("binds", "workspace_center_on") => "0",
+        ("binds", "focus_preferred_method") => "0",
+        ("binds", "ignore_group_lock") => "false",
+        ("binds", "movefocus_cycles_fullscreen") => "true",
+        ("binds", "disable_keybind_grabbing") => "false",
+        ("binds", "window_direction_monitor_fallback") => "true",
+
+        ("xwayland", "enabled") => "true",
+        ("xwayland", "use_nearest_neighbor") => "true",
+        ("xwayland", "force_zero_scaling") => "false",
+
+        ("opengl", "nvidia_anti_flicker") => "true",
+        ("opengl", "force_introspection") => "2",
+
+        ("render", "explicit_sync") => "2",
+        ("render", "explicit_sync_kms") => "2",
+        ("render", "direct_scanout") => "false",
+
+        ("cursor", "sync_gsettings_theme") => "true",
+        ("cursor", "no_hardware_cursors") => "false",
+        ("cursor", "no_break_fs_vrr") => "false",
+        ("cursor", "min_refresh_rate") => "24",
+        ("cursor", "hotspot_padding") => "1",
+        ("cursor", "inactive_timeout") => "0.0",
+        ("cursor", "no_warps") => "false",
+        ("cursor", "persistent_warps") => "false",
+        ("cursor", "warp_on_change_workspace") => "false",
+        ("cursor", "zoom_factor") => "1.0",
+        ("cursor", "zoom_rigid") => "false",
+        ("cursor", "enable_hyprcursor") => "true",
+        ("cursor", "hide_on_key_press") => "false",
+        ("cursor", "hide_on_touch") => "true",
+        ("cursor", "use_cpu_buffer") => "false",
+
+        ("debug", "overlay") => "false",
+        ("debug", "damage_blink") => "false",
+        ("debug", "disable_logs") => "true",
+        ("debug", "disable_time") => "true",
+        ("debug", "damage_tracking") => "2",
+        ("debug", "enable_stdout_logs") => "false",
+        ("debug", "manual_crash") => "0",
+        ("debug", "suppress_errors") => "false",
+        ("debug", "watchdog_timeout") => "5",
+        ("debug", "disable_scale_checks") => "false",
+        ("debug", "error_limit") => "5",
+        ("debug", "error_position") => "0",
+        ("debug", "colored_stdout_logs") => "true",
+
+        ("layouts", "dwindle:pseudotile") => "false",
+        ("layouts", "dwindle:force_split") => "0",
+        ("layouts", "dwindle:preserve_split") => "false",
+        ("layouts", "dwindle:smart_split") => "false",
+        ("layouts", "dwindle:smart_resizing") => "true",
+        ("layouts", "dwindle:permanent_direction_override") => "false",
+        ("layouts", "dwindle:special_scale_factor") => "1.0",
+        ("layouts", "dwindle:split_width_multiplier") => "1.0",
+        ("layouts", "dwindle:use_active_for_splits") => "true",
+        ("layouts", "dwindle:default_split_ratio") => "1.0",
+        ("layouts", "dwindle:split_bias") => "0",
+        ("layouts", "master:allow_small_split") => "false",
+        ("layouts", "master:special_scale_factor") => "1.0",
+        ("layouts", "master:mfact") => "0.55",
+        ("layouts", "master:new_status") => "slave",
+        ("layouts", "master:new_on_top") => "false",
+        ("layouts", "master:new_on_active") => "none",
+        ("layouts", "master:orientation") => "left",
+        ("layouts", "master:inherit_fullscreen") => "true",
+        ("layouts", "master:always_center_master") => "false",
+        ("layouts", "master:smart_resizing") => "true",
+        ("layouts", "master:drop_at_cursor") => "true",
+
+        _ => return None,
+    };
+    Some(default)
+}
+
+pub fn parse_bool(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "true" | "1" | "yes" | "on"
+    )
+}
+
+pub fn is_default_value(value: &str, default: &str) -> bool {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case(default) {
+        return true;
+    }
+    if let (Ok(a), Ok(b)) = (value.parse::<f64>(), default.parse::<f64>()) {
+        return (a - b).abs() < 1e-6;
+    }
+    let is_bool = |v: &str| {
+        matches!(
+            v.to_lowercase().as_str(),
+            "true" | "false" | "yes" | "no" | "on" | "off"
+        )
+    };
+    if is_bool(value) && is_bool(default) {
+        return parse_bool(value) == parse_bool(default);
+    }
+    let parser = HyprlandConfig::new();
+    match (parser.parse_color(value), parser.parse_color(default)) {
+        (Some(a), Some(b)) => {
+            let channels = |c: (f32, f32, f32, f32)| {
+                [c.0, c.1, c.2, c.3].map(|channel| (channel * 255.0).round() as u8)
+            };
+            channels(a) == channels(b)
+        }
+        _ => false,
+    }
+}
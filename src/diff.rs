@@ -0,0 +1,470 @@
+use gtk::{glib, prelude::*, Box, Button, CheckButton, Frame, Label, Orientation, ScrolledWindow};
+use std::ops::Range;
+use std::path::PathBuf;
+
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+impl Hunk {
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start + 1,
+            self.old_len,
+            self.new_start + 1,
+            self.new_len
+        )
+    }
+
+    fn old_lines(&self) -> impl Iterator<Item = &String> {
+        self.lines.iter().filter_map(|line| match line {
+            DiffLine::Context(text) | DiffLine::Removed(text) => Some(text),
+            DiffLine::Added(_) => None,
+        })
+    }
+
+    pub fn overlaps(&self, range: &Range<usize>) -> bool {
+        let end = self.old_start + self.old_len;
+        if range.is_empty() {
+            self.old_start <= range.start && range.start <= end
+        } else {
+            range.start < end && self.old_start < range.end
+        }
+    }
+
+    fn new_lines(&self) -> impl Iterator<Item = &String> {
+        self.lines.iter().filter_map(|line| match line {
+            DiffLine::Context(text) | DiffLine::Added(text) => Some(text),
+            DiffLine::Removed(_) => None,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+fn edit_script(old: &[String], new: &[String]) -> Vec<Op> {
+    let (n, m) = (old.len(), new.len());
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * (m + 1) + j] = if old[i] == new[j] {
+                lcs[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old[i] == new[j] {
+            ops.push(Op::Equal);
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i * (m + 1) + j + 1] >= lcs[(i + 1) * (m + 1) + j]) {
+            ops.push(Op::Insert);
+            j += 1;
+        } else {
+            ops.push(Op::Delete);
+            i += 1;
+        }
+    }
+    ops
+}
+
+pub fn diff_hunks(old: &[String], new: &[String]) -> Vec<Hunk> {
+    let ops = edit_script(old, new);
+
+    let changes: Vec<usize> = (0..ops.len()).filter(|&k| ops[k] != Op::Equal).collect();
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for &k in &changes {
+        match groups.last_mut() {
+            Some((_, end)) if k - *end <= 2 * CONTEXT_LINES + 1 => *end = k,
+            _ => groups.push((k, k)),
+        }
+    }
+
+    let mut positions = Vec::with_capacity(ops.len() + 1);
+    let (mut i, mut j) = (0, 0);
+    for op in &ops {
+        positions.push((i, j));
+        match op {
+            Op::Equal => {
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+    positions.push((i, j));
+
+    groups
+        .into_iter()
+        .map(|(first, last)| {
+            let start = first.saturating_sub(CONTEXT_LINES);
+            let end = (last + 1 + CONTEXT_LINES).min(ops.len());
+            let (old_start, new_start) = positions[start];
+            let (old_end, new_end) = positions[end];
+            let lines = (start..end)
+                .map(|k| {
+                    let (i, j) = positions[k];
+                    match ops[k] {
+                        Op::Equal => DiffLine::Context(old[i].clone()),
+                        Op::Delete => DiffLine::Removed(old[i].clone()),
+                        Op::Insert => DiffLine::Added(new[j].clone()),
+                    }
+                })
+                .collect();
+            Hunk {
+                old_start,
+                old_len: old_end - old_start,
+                new_start,
+                new_len: new_end - new_start,
+                lines,
+            }
+        })
+        .collect()
+}
+
+pub fn apply_hunks(old: &[String], hunks: &[Hunk], accepted: &[bool]) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut position = 0;
+    for (hunk, accepted) in hunks.iter().zip(accepted) {
+        result.extend_from_slice(&old[position..hunk.old_start]);
+        if *accepted {
+            result.extend(hunk.new_lines().cloned());
+        } else {
+            result.extend(hunk.old_lines().cloned());
+        }
+        position = hunk.old_start + hunk.old_len;
+    }
+    result.extend_from_slice(&old[position..]);
+    result
+}
+
+fn matches(old: &[String], new: &[String]) -> Vec<Option<usize>> {
+    let mut matched = vec![None; old.len()];
+    let (mut i, mut j) = (0, 0);
+    for op in edit_script(old, new) {
+        match op {
+            Op::Equal => {
+                matched[i] = Some(j);
+                i += 1;
+                j += 1;
+            }
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+    matched
+}
+
+fn merge_chunk(
+    base: &[String],
+    ours: &[String],
+    theirs: &[String],
+    merged: &mut Vec<String>,
+) -> bool {
+    if ours == base || ours == theirs {
+        merged.extend_from_slice(theirs);
+        false
+    } else if theirs == base {
+        merged.extend_from_slice(ours);
+        false
+    } else {
+        merged.extend_from_slice(ours);
+        true
+    }
+}
+
+pub fn merge3(
+    base: &[String],
+    ours: &[String],
+    theirs: &[String],
+) -> (Vec<String>, Vec<Range<usize>>) {
+    let ours_matches = matches(base, ours);
+    let theirs_matches = matches(base, theirs);
+
+    let mut merged = Vec::new();
+    let mut conflicts = Vec::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    for k in 0..base.len() {
+        if let (Some(ok), Some(tk)) = (ours_matches[k], theirs_matches[k]) {
+            if merge_chunk(&base[b..k], &ours[o..ok], &theirs[t..tk], &mut merged) {
+                conflicts.push(t..tk);
+            }
+            merged.push(base[k].clone());
+            (b, o, t) = (k + 1, ok + 1, tk + 1);
+        }
+    }
+    if merge_chunk(&base[b..], &ours[o..], &theirs[t..], &mut merged) {
+        conflicts.push(t..theirs.len());
+    }
+    (merged, conflicts)
+}
+
+pub struct PendingFile {
+    pub path: PathBuf,
+    pub label: String,
+    pub old: Vec<String>,
+    pub hunks: Vec<Hunk>,
+    pub conflicts: Vec<Range<usize>>,
+}
+
+pub fn hunk_markup(hunk: &Hunk) -> String {
+    let mut markup = format!("<b>{}</b>", glib::markup_escape_text(&hunk.header()));
+    for line in &hunk.lines {
+        let (prefix, color, text) = match line {
+            DiffLine::Context(text) => (" ", None, text),
+            DiffLine::Removed(text) => ("-", Some("#e01b24"), text),
+            DiffLine::Added(text) => ("+", Some("#2ec27e"), text),
+        };
+        let text = glib::markup_escape_text(&format!("{}{}", prefix, text));
+        markup.push('\n');
+        match color {
+            Some(color) => {
+                markup.push_str(&format!("<span foreground=\"{}\">{}</span>", color, text))
+            }
+            None => markup.push_str(&text),
+        }
+    }
+    markup
+}
+
+pub fn show_diff_dialog(
+    parent: &impl IsA<gtk::Window>,
+    files: Vec<PendingFile>,
+    on_confirm: impl Fn(Vec<(PathBuf, Vec<String>)>) + 'static,
+) {
+    let window = gtk::Window::builder()
+        .title("Review Changes")
+        .modal(true)
+        .transient_for(parent)
+        .default_width(800)
+        .default_height(600)
+        .build();
+
+    let vbox = Box::new(Orientation::Vertical, 10);
+    vbox.set_margin_start(10);
+    vbox.set_margin_end(10);
+    vbox.set_margin_top(10);
+    vbox.set_margin_bottom(10);
+
+    let files_box = Box::new(Orientation::Vertical, 10);
+    let scrolled_window = ScrolledWindow::new();
+    scrolled_window.set_vexpand(true);
+    scrolled_window.set_child(Some(&files_box));
+
+    let conflict_count: usize = files
+        .iter()
+        .map(|file| {
+            file.hunks
+                .iter()
+                .filter(|hunk| file.conflicts.iter().any(|range| hunk.overlaps(range)))
+                .count()
+        })
+        .sum();
+    if conflict_count > 0 {
+        let warning_label = Label::new(Some(&format!(
+            "{} change{} conflict{} with edits made on disk. They are marked below and left unchecked, so the version on disk is kept unless you check them.",
+            conflict_count,
+            if conflict_count == 1 { "" } else { "s" },
+            if conflict_count == 1 { "s" } else { "" },
+        )));
+        warning_label.set_halign(gtk::Align::Start);
+        warning_label.set_wrap(true);
+        warning_label.add_css_class("warning");
+        vbox.append(&warning_label);
+    }
+
+    let mut checks: Vec<Vec<CheckButton>> = Vec::new();
+    for file in &files {
+        let file_label = Label::new(None);
+        file_label.set_markup(&format!("<b>{}</b>", glib::markup_escape_text(&file.label)));
+        file_label.set_halign(gtk::Align::Start);
+        files_box.append(&file_label);
+
+        let mut file_checks = Vec::new();
+        for hunk in &file.hunks {
+            let hunk_box = Box::new(Orientation::Vertical, 5);
+            hunk_box.set_margin_start(5);
+            hunk_box.set_margin_end(5);
+            hunk_box.set_margin_top(5);
+            hunk_box.set_margin_bottom(5);
+
+            let conflict = file.conflicts.iter().any(|range| hunk.overlaps(range));
+            let check = CheckButton::with_label(if conflict {
+                "Apply this change (conflicts with edits on disk)"
+            } else {
+                "Apply this change"
+            });
+            check.set_active(!conflict);
+
+            let diff_label = Label::new(None);
+            diff_label.set_markup(&hunk_markup(hunk));
+            diff_label.set_halign(gtk::Align::Start);
+            diff_label.set_selectable(true);
+            diff_label.add_css_class("monospace");
+
+            diff_label.set_opacity(if conflict { 0.4 } else { 1.0 });
+
+            let diff_label_clone = diff_label.clone();
+            check.connect_toggled(move |check| {
+                diff_label_clone.set_opacity(if check.is_active() { 1.0 } else { 0.4 });
+            });
+
+            hunk_box.append(&check);
+            hunk_box.append(&diff_label);
+
+            let frame = Frame::new(None);
+            if conflict {
+                frame.add_css_class("warning");
+            }
+            frame.set_child(Some(&hunk_box));
+            files_box.append(&frame);
+            file_checks.push(check);
+        }
+        checks.push(file_checks);
+    }
+
+    let button_box = Box::new(Orientation::Horizontal, 5);
+    button_box.set_halign(gtk::Align::End);
+    let cancel_button = Button::with_label("Cancel");
+    let save_button = Button::with_label("Save Selected");
+    save_button.add_css_class("suggested-action");
+    button_box.append(&cancel_button);
+    button_box.append(&save_button);
+
+    vbox.append(&scrolled_window);
+    vbox.append(&button_box);
+    window.set_child(Some(&vbox));
+
+    let window_clone = window.clone();
+    cancel_button.connect_clicked(move |_| {
+        window_clone.close();
+    });
+
+    let window_clone = window.clone();
+    save_button.connect_clicked(move |_| {
+        let writes = files
+            .iter()
+            .zip(&checks)
+            .filter_map(|(file, file_checks)| {
+                let accepted: Vec<bool> = file_checks.iter().map(|c| c.is_active()).collect();
+                if !accepted.contains(&true) {
+                    return None;
+                }
+                let lines = apply_hunks(&file.old, &file.hunks, &accepted);
+                Some((file.path.clone(), lines))
+            })
+            .collect();
+        window_clone.close();
+        on_confirm(writes);
+    });
+
+    window.present();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn identical_files_have_no_hunks() {
+        let old = lines("a\nb\nc");
+        assert!(diff_hunks(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn accepting_every_hunk_reproduces_the_new_file() {
+        let old = lines("a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl");
+        let new = lines("a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nl\nm");
+        let hunks = diff_hunks(&old, &new);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].header(), "@@ -1,5 +1,5 @@");
+
+        assert_eq!(apply_hunks(&old, &hunks, &[true, true]), new);
+        assert_eq!(apply_hunks(&old, &hunks, &[false, false]), old);
+    }
+
+    #[test]
+    fn rejected_hunks_keep_the_old_lines() {
+        let old = lines("a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl");
+        let new = lines("a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nL");
+        let hunks = diff_hunks(&old, &new);
+        assert_eq!(
+            apply_hunks(&old, &hunks, &[true, false]),
+            lines("a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl")
+        );
+    }
+
+    #[test]
+    fn merge3_combines_independent_edits() {
+        let base = lines("a\nb\nc\nd\ne");
+        let ours = lines("a\nB\nc\nd\ne");
+        let theirs = lines("a\nb\nc\nd\nE\nf");
+        let (merged, conflicts) = merge3(&base, &ours, &theirs);
+        assert_eq!(merged, lines("a\nB\nc\nd\nE\nf"));
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn merge3_accepts_the_same_edit_on_both_sides() {
+        let base = lines("a\nb\nc");
+        let edited = lines("a\nx\nc");
+        assert_eq!(
+            merge3(&base, &edited, &edited),
+            (edited.clone(), Vec::new())
+        );
+    }
+
+    #[test]
+    fn merge3_reports_conflicts_and_keeps_ours() {
+        let base = lines("a\nb\nc");
+        let ours = lines("a\nours\nc");
+        let theirs = lines("a\ntheirs\nc");
+        let (merged, conflicts) = merge3(&base, &ours, &theirs);
+        assert_eq!(merged, ours);
+        assert_eq!(conflicts, vec![1..2]);
+
+        let hunks = diff_hunks(&theirs, &merged);
+        assert!(hunks[0].overlaps(&conflicts[0]));
+    }
+
+    #[test]
+    fn hunks_away_from_a_conflict_do_not_overlap_it() {
+        let theirs = lines("a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl");
+        let merged = lines("A\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nL");
+        let hunks = diff_hunks(&theirs, &merged);
+        assert_eq!(hunks.len(), 2);
+        assert!(hunks[0].overlaps(&(0..1)));
+        assert!(!hunks[1].overlaps(&(0..1)));
+        assert!(hunks[1].overlaps(&(12..12)));
+    }
+}
@@ -0,0 +1,865 @@
+use hyprparser::HyprlandConfig;
+use std::env;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Directive {
+    pub keyword: String,
+    pub value: String,
+    pub file: usize,
+    pub line: Option<usize>,
+    pub submap: Option<String>,
+    pub disabled: bool,
+    raw: Option<String>,
+}
+
+impl Directive {
+    pub fn new(keyword: &str, value: &str, file: usize, submap: Option<String>) -> Self {
+        Self {
+            keyword: keyword.to_string(),
+            value: value.to_string(),
+            file,
+            line: None,
+            submap,
+            disabled: false,
+            raw: None,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        if let Some(raw) = &self.raw {
+            let parsed = if self.disabled {
+                parse_disabled_line(raw)
+            } else {
+                parse_line(raw)
+            };
+            if parsed == Some((self.keyword.clone(), self.value.clone())) {
+                return raw.trim_start().to_string();
+            }
+        }
+        if self.disabled {
+            format!("# {} = {}", self.keyword, self.value)
+        } else {
+            format!("{} = {}", self.keyword, self.value)
+        }
+    }
+}
+
+pub fn parse_line(line: &str) -> Option<(String, String)> {
+    let trimmed = line.trim();
+    if trimmed.starts_with('#') {
+        return None;
+    }
+    let (keyword, value) = trimmed.split_once('=')?;
+    let keyword = keyword.trim();
+    if keyword.is_empty() || keyword.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((keyword.to_string(), strip_comment(value).trim().to_string()))
+}
+
+pub fn parse_disabled_line(line: &str) -> Option<(String, String)> {
+    let uncommented = line.trim_start().strip_prefix('#')?.trim_start_matches('#');
+    parse_line(uncommented)
+}
+
+pub fn strip_comment(value: &str) -> &str {
+    let bytes = value.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'#' {
+            if bytes.get(i + 1) == Some(&b'#') {
+                i += 2;
+                continue;
+            }
+            return &value[..i];
+        }
+        i += 1;
+    }
+    value
+}
+
+struct ScannedLine {
+    line: usize,
+    keyword: String,
+    value: String,
+    submap: Option<String>,
+    disabled: bool,
+}
+
+fn scan(
+    lines: &[String],
+    matches: &dyn Fn(&str) -> bool,
+    include_disabled: bool,
+) -> Vec<ScannedLine> {
+    let mut scanned = Vec::new();
+    let mut depth = 0usize;
+    let mut submap = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+
+        if depth == 0 {
+            if let Some((keyword, value)) = parse_line(trimmed) {
+                if keyword == "submap" {
+                    submap = if value == "reset" { None } else { Some(value) };
+                    continue;
+                }
+                if matches(&keyword) {
+                    scanned.push(ScannedLine {
+                        line: i,
+                        keyword,
+                        value,
+                        submap: submap.clone(),
+                        disabled: false,
+                    });
+                    continue;
+                }
+            }
+            if include_disabled {
+                if let Some((keyword, value)) = parse_disabled_line(trimmed) {
+                    if matches(&keyword) {
+                        scanned.push(ScannedLine {
+                            line: i,
+                            keyword,
+                            value,
+                            submap: submap.clone(),
+                            disabled: true,
+                        });
+                        continue;
+                    }
+                }
+            }
+        }
+
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.ends_with('{') {
+            depth += 1;
+        } else if trimmed.starts_with('}') {
+            depth = depth.saturating_sub(1);
+        }
+    }
+
+    scanned
+}
+
+pub fn file_count(config: &HyprlandConfig) -> usize {
+    1 + config.sourced_content.len()
+}
+
+pub fn file_lines(config: &HyprlandConfig, file: usize) -> &[String] {
+    if file == 0 {
+        &config.content
+    } else {
+        &config.sourced_content[file - 1]
+    }
+}
+
+fn file_lines_mut(config: &mut HyprlandConfig, file: usize) -> &mut Vec<String> {
+    if file == 0 {
+        &mut config.content
+    } else {
+        &mut config.sourced_content[file - 1]
+    }
+}
+
+pub fn file_labels(config: &HyprlandConfig) -> Vec<String> {
+    let home = env::var("HOME").unwrap_or_default();
+    let mut labels = vec!["hyprland.conf".to_string()];
+    for path in &config.sourced_paths {
+        if !home.is_empty() && path.starts_with(&home) {
+            labels.push(format!("~{}", &path[home.len()..]));
+        } else {
+            labels.push(path.clone());
+        }
+    }
+    labels
+}
+
+pub fn parse_snapshot(main: &str, sourced: &[(String, String)]) -> HyprlandConfig {
+    let mut main_config = HyprlandConfig::new();
+    main_config.parse(main, true);
+
+    let mut config = HyprlandConfig::new();
+    config.content = main_config.sourced_content.pop().unwrap_or_default();
+    config.sections = main_config
+        .sourced_sections
+        .into_iter()
+        .filter_map(|(name, range)| Some((name.strip_suffix("_0")?.to_string(), range)))
+        .collect();
+    for (path, content) in sourced {
+        config.parse(content, true);
+        config.sourced_paths.push(path.clone());
+    }
+    config
+}
+
+pub fn submap_names(config: &HyprlandConfig) -> Vec<String> {
+    let mut names = Vec::new();
+    for file in 0..file_count(config) {
+        for line in file_lines(config, file) {
+            if let Some((keyword, value)) = parse_line(line) {
+                if keyword == "submap" && value != "reset" && !names.contains(&value) {
+                    names.push(value);
+                }
+            }
+        }
+    }
+    names
+}
+
+pub fn collect(config: &HyprlandConfig, matches: &dyn Fn(&str) -> bool) -> Vec<Directive> {
+    collect_directives(config, matches, false)
+}
+
+pub fn collect_with_disabled(
+    config: &HyprlandConfig,
+    matches: &dyn Fn(&str) -> bool,
+) -> Vec<Directive> {
+    collect_directives(config, matches, true)
+}
+
+fn collect_directives(
+    config: &HyprlandConfig,
+    matches: &dyn Fn(&str) -> bool,
+    include_disabled: bool,
+) -> Vec<Directive> {
+    let mut directives = Vec::new();
+    for file in 0..file_count(config) {
+        let lines = file_lines(config, file);
+        for scanned in scan(lines, matches, include_disabled) {
+            directives.push(Directive {
+                keyword: scanned.keyword,
+                value: scanned.value,
+                file,
+                line: Some(scanned.line),
+                submap: scanned.submap,
+                disabled: scanned.disabled,
+                raw: Some(lines[scanned.line].clone()),
+            });
+        }
+    }
+    directives
+}
+
+struct Edit {
+    start: usize,
+    remove: usize,
+    insert: Vec<String>,
+}
+
+pub fn apply(config: &mut HyprlandConfig, matches: &dyn Fn(&str) -> bool, wanted: &[Directive]) {
+    apply_directives(config, matches, wanted, false);
+}
+
+pub fn apply_with_disabled(
+    config: &mut HyprlandConfig,
+    matches: &dyn Fn(&str) -> bool,
+    wanted: &[Directive],
+) {
+    apply_directives(config, matches, wanted, true);
+}
+
+fn apply_directives(
+    config: &mut HyprlandConfig,
+    matches: &dyn Fn(&str) -> bool,
+    wanted: &[Directive],
+    include_disabled: bool,
+) {
+    for file in 0..file_count(config) {
+        let lines = file_lines(config, file);
+        let existing = scan(lines, matches, include_disabled);
+
+        let mut scopes: Vec<Option<String>> = Vec::new();
+        for scope in existing
+            .iter()
+            .map(|s| &s.submap)
+            .chain(wanted.iter().filter(|d| d.file == file).map(|d| &d.submap))
+        {
+            if !scopes.contains(scope) {
+                scopes.push(scope.clone());
+            }
+        }
+
+        let mut edits = Vec::new();
+        for scope in &scopes {
+            let slots: Vec<usize> = existing
+                .iter()
+                .filter(|s| &s.submap == scope)
+                .map(|s| s.line)
+                .collect();
+            let rendered: Vec<String> = wanted
+                .iter()
+                .filter(|d| d.file == file && &d.submap == scope)
+                .map(|d| d.render())
+                .collect();
+
+            for (slot, line) in slots.iter().zip(rendered.iter()) {
+                let indent = &lines[*slot][..lines[*slot].len() - lines[*slot].trim_start().len()];
+                edits.push(Edit {
+                    start: *slot,
+                    remove: 1,
+                    insert: vec![format!("{}{}", indent, line)],
+                });
+            }
+            for slot in slots.iter().skip(rendered.len()) {
+                edits.push(Edit {
+                    start: *slot,
+                    remove: 1,
+                    insert: Vec::new(),
+                });
+            }
+
+            let extra: Vec<String> = rendered.iter().skip(slots.len()).cloned().collect();
+            if extra.is_empty() {
+                continue;
+            }
+
+            if let Some(last) = slots.last() {
+                edits.push(Edit {
+                    start: last + 1,
+                    remove: 0,
+                    insert: extra,
+                });
+            } else if let Some(name) = scope {
+                let header = lines
+                    .iter()
+                    .position(|l| parse_line(l) == Some(("submap".to_string(), name.clone())));
+                match header {
+                    Some(pos) => edits.push(Edit {
+                        start: pos + 1,
+                        remove: 0,
+                        insert: extra,
+                    }),
+                    None => {
+                        let mut block = Vec::new();
+                        if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                            block.push(String::new());
+                        }
+                        block.push(format!("submap = {}", name));
+                        block.extend(extra);
+                        block.push("submap = reset".to_string());
+                        edits.push(Edit {
+                            start: lines.len(),
+                            remove: 0,
+                            insert: block,
+                        });
+                    }
+                }
+            } else {
+                let global_end = lines
+                    .iter()
+                    .position(|l| parse_line(l).is_some_and(|(k, v)| k == "submap" && v != "reset"))
+                    .unwrap_or(lines.len());
+                edits.push(Edit {
+                    start: global_end,
+                    remove: 0,
+                    insert: extra,
+                });
+            }
+        }
+
+        edits.sort_by(|a, b| b.start.cmp(&a.start).then(b.remove.cmp(&a.remove)));
+        for edit in edits {
+            let delta = edit.insert.len() as isize - edit.remove as isize;
+            file_lines_mut(config, file).splice(edit.start..edit.start + edit.remove, edit.insert);
+            shift_sections(config, file, edit.start, delta);
+        }
+    }
+}
+
+pub fn shift_sections(config: &mut HyprlandConfig, file: usize, pos: usize, delta: isize) {
+    if delta == 0 {
+        return;
+    }
+    let shift = |value: &mut usize| {
+        *value = (*value as isize + delta).max(0) as usize;
+    };
+    if file == 0 {
+        for (start, end) in config.sections.values_mut() {
+            if *start >= pos {
+                shift(start);
+                shift(end);
+            } else if *end >= pos {
+                shift(end);
+            }
+        }
+    } else {
+        let index = (file - 1).to_string();
+        for (key, (start, end)) in config.sourced_sections.iter_mut() {
+            if key.rsplit_once('_').is_none_or(|(_, idx)| idx != index) {
+                continue;
+            }
+            if *start >= pos {
+                shift(start);
+                shift(end);
+            } else if *end >= pos {
+                shift(end);
+            }
+        }
+    }
+}
+
+fn section_range(config: &HyprlandConfig, file: usize, section: &str) -> Option<(usize, usize)> {
+    let range = if file == 0 {
+        config.sections.get(section)
+    } else {
+        config
+            .sourced_sections
+            .get(&format!("{}_{}", section, file - 1))
+    };
+    let &(start, end) = range?;
+    let lines = file_lines(config, file);
+    let is_block = start < end
+        && end < lines.len()
+        && lines[start].trim().ends_with('{')
+        && lines[end].trim().starts_with('}');
+    is_block.then_some((start, end))
+}
+
+fn forget_section(config: &mut HyprlandConfig, file: usize, section: &str) {
+    if file == 0 {
+        config.sections.remove(section);
+    } else {
+        config
+            .sourced_sections
+            .remove(&format!("{}_{}", section, file - 1));
+    }
+}
+
+fn option_lines(lines: &[String], start: usize, end: usize, key: &str) -> Vec<usize> {
+    let mut depth = 0usize;
+    let mut found = Vec::new();
+    for (i, line) in lines[start + 1..end].iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.ends_with('{') {
+            depth += 1;
+        } else if trimmed.starts_with('}') {
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 && parse_line(trimmed).is_some_and(|(k, _)| k == key) {
+            found.push(start + 1 + i);
+        }
+    }
+    found
+}
+
+fn colon_option_lines(lines: &[String], section: &str, key: &str) -> Vec<usize> {
+    let full_key = format!("{}:{}", section.replace('.', ":"), key);
+    let mut depth = 0usize;
+    let mut found = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed.ends_with('{') {
+            depth += 1;
+        } else if trimmed.starts_with('}') {
+            depth = depth.saturating_sub(1);
+        } else if depth == 0 && parse_line(trimmed).is_some_and(|(k, _)| k == full_key) {
+            found.push(i);
+        }
+    }
+    found
+}
+
+fn all_option_lines(config: &HyprlandConfig, file: usize, section: &str, key: &str) -> Vec<usize> {
+    let lines = file_lines(config, file);
+    let mut found = colon_option_lines(lines, section, key);
+    if let Some((start, end)) = section_range(config, file, section) {
+        found.extend(option_lines(lines, start, end, key));
+    }
+    found.sort_unstable();
+    found
+}
+
+pub fn find_option(config: &HyprlandConfig, section: &str, key: &str) -> Option<(usize, usize)> {
+    (0..file_count(config)).find_map(|file| {
+        let line = *all_option_lines(config, file, section, key).last()?;
+        Some((file, line))
+    })
+}
+
+pub fn set_option(
+    config: &mut HyprlandConfig,
+    target: usize,
+    section: &str,
+    key: &str,
+    value: &str,
+) {
+    if let Some((file, line)) = find_option(config, section, key) {
+        let lines = file_lines_mut(config, file);
+        let original = &lines[line];
+        let indent = &original[..original.len() - original.trim_start().len()];
+        let (name, after) = original.split_once('=').unwrap_or((key, ""));
+        let name = name.trim().to_string();
+        let comment = after[strip_comment(after).len()..].trim();
+        lines[line] = if comment.is_empty() {
+            format!("{}{} = {}", indent, name, value)
+        } else {
+            format!("{}{} = {} {}", indent, name, value, comment)
+        };
+        return;
+    }
+
+    let target = if target < file_count(config) {
+        target
+    } else {
+        0
+    };
+    let end = ensure_section(config, target, section);
+    let indent = "    ".repeat(section.matches('.').count() + 1);
+    file_lines_mut(config, target).insert(end, format!("{}{} = {}", indent, key, value));
+    shift_sections(config, target, end, 1);
+}
+
+fn ensure_section(config: &mut HyprlandConfig, file: usize, section: &str) -> usize {
+    if let Some((_, end)) = section_range(config, file, section) {
+        return end;
+    }
+
+    let (position, name) = match section.rsplit_once('.') {
+        Some((parent, name)) => (ensure_section(config, file, parent), name),
+        None => {
+            let lines = file_lines_mut(config, file);
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            (lines.len(), section)
+        }
+    };
+
+    let indent = "    ".repeat(section.matches('.').count());
+    let lines = file_lines_mut(config, file);
+    lines.insert(position, format!("{}}}", indent));
+    lines.insert(position, format!("{}{} {{", indent, name));
+    shift_sections(config, file, position, 2);
+
+    let range = (position, position + 1);
+    if file == 0 {
+        config.sections.insert(section.to_string(), range);
+    } else {
+        config
+            .sourced_sections
+            .insert(format!("{}_{}", section, file - 1), range);
+    }
+    position + 1
+}
+
+pub fn remove_option(config: &mut HyprlandConfig, section: &str, key: &str) {
+    for file in 0..file_count(config) {
+        remove_option_in(config, file, section, key);
+    }
+}
+
+fn remove_option_in(config: &mut HyprlandConfig, file: usize, section: &str, key: &str) {
+    let found = all_option_lines(config, file, section, key);
+    if found.is_empty() {
+        return;
+    }
+    for line in found.into_iter().rev() {
+        file_lines_mut(config, file).remove(line);
+        shift_sections(config, file, line, -1);
+    }
+    remove_empty_section(config, file, section);
+}
+
+fn remove_empty_section(config: &mut HyprlandConfig, file: usize, section: &str) {
+    let (start, end) = match section_range(config, file, section) {
+        Some(range) => range,
+        None => return,
+    };
+    if file_lines(config, file)[start + 1..end]
+        .iter()
+        .any(|line| !line.trim().is_empty())
+    {
+        return;
+    }
+
+    forget_section(config, file, section);
+    file_lines_mut(config, file).drain(start..=end);
+    shift_sections(config, file, start, -((end - start + 1) as isize));
+
+    let lines = file_lines(config, file);
+    let blank_at = |i: usize| lines.get(i).is_some_and(|line| line.trim().is_empty());
+    let leading_blank = blank_at(start)
+        && (start == 0 || blank_at(start - 1) || lines[start - 1].trim().ends_with('{'));
+    let trailing_blank = start > 0
+        && blank_at(start - 1)
+        && lines
+            .get(start)
+            .is_none_or(|line| line.trim().starts_with('}'));
+    if leading_blank {
+        file_lines_mut(config, file).remove(start);
+        shift_sections(config, file, start, -1);
+    } else if trailing_blank {
+        file_lines_mut(config, file).remove(start - 1);
+        shift_sections(config, file, start - 1, -1);
+    }
+
+    if let Some((parent, _)) = section.rsplit_once('.') {
+        remove_empty_section(config, file, parent);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+    pub file: usize,
+    pub line: Option<usize>,
+    pub entries: Vec<(String, String)>,
+}
+
+struct ScannedBlock {
+    start: usize,
+    end: usize,
+    entries: Vec<(String, String)>,
+}
+
+fn scan_blocks(lines: &[String], name: &str) -> Vec<ScannedBlock> {
+    let mut blocks = Vec::new();
+    let mut depth = 0usize;
+    let mut current: Option<ScannedBlock> = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            continue;
+        }
+
+        if trimmed.ends_with('{') {
+            if depth == 0 && trimmed.trim_end_matches('{').trim() == name {
+                current = Some(ScannedBlock {
+                    start: i,
+                    end: i,
+                    entries: Vec::new(),
+                });
+            }
+            depth += 1;
+        } else if trimmed.starts_with('}') {
+            depth = depth.saturating_sub(1);
+            if depth == 0 {
+                if let Some(mut block) = current.take() {
+                    block.end = i;
+                    blocks.push(block);
+                }
+            }
+        } else if depth == 1 {
+            if let (Some(block), Some(entry)) = (current.as_mut(), parse_line(trimmed)) {
+                block.entries.push(entry);
+            }
+        }
+    }
+
+    blocks
+}
+
+pub fn collect_blocks(config: &HyprlandConfig, name: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    for file in 0..file_count(config) {
+        for scanned in scan_blocks(file_lines(config, file), name) {
+            blocks.push(Block {
+                file,
+                line: Some(scanned.start),
+                entries: scanned.entries,
+            });
+        }
+    }
+    blocks
+}
+
+fn render_block(name: &str, indent: &str, entries: &[(String, String)]) -> Vec<String> {
+    let mut lines = vec![format!("{}{} {{", indent, name)];
+    for (key, value) in entries {
+        lines.push(format!("{}    {} = {}", indent, key, value));
+    }
+    lines.push(format!("{}}}", indent));
+    lines
+}
+
+fn update_block(lines: &[String], entries: &[(String, String)]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut wanted = entries.iter();
+    let mut entry_indent = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let indent = &line[..line.len() - line.trim_start().len()];
+
+        if i == lines.len() - 1 {
+            let indent = entry_indent
+                .clone()
+                .unwrap_or_else(|| format!("{}    ", indent));
+            for (key, value) in wanted.by_ref() {
+                out.push(format!("{}{} = {}", indent, key, value));
+            }
+            out.push(line.clone());
+            break;
+        }
+
+        if trimmed.starts_with('#') {
+            out.push(line.clone());
+        } else if trimmed.ends_with('{') {
+            depth += 1;
+            out.push(line.clone());
+        } else if trimmed.starts_with('}') {
+            depth = depth.saturating_sub(1);
+            out.push(line.clone());
+        } else if depth == 1 && parse_line(trimmed).is_some() {
+            entry_indent.get_or_insert_with(|| indent.to_string());
+            if let Some((key, value)) = wanted.next() {
+                if parse_line(trimmed) == Some((key.clone(), value.clone())) {
+                    out.push(line.clone());
+                } else {
+                    out.push(format!("{}{} = {}", indent, key, value));
+                }
+            }
+        } else {
+            out.push(line.clone());
+        }
+    }
+    out
+}
+
+pub fn apply_blocks(config: &mut HyprlandConfig, name: &str, wanted: &[Block]) {
+    for file in 0..file_count(config) {
+        let lines = file_lines(config, file);
+        let existing = scan_blocks(lines, name);
+        let rendered: Vec<&Block> = wanted.iter().filter(|b| b.file == file).collect();
+
+        let mut edits = Vec::new();
+        for (slot, block) in existing.iter().zip(rendered.iter()) {
+            if slot.entries == block.entries {
+                continue;
+            }
+            edits.push(Edit {
+                start: slot.start,
+                remove: slot.end - slot.start + 1,
+                insert: update_block(&lines[slot.start..=slot.end], &block.entries),
+            });
+        }
+        for slot in existing.iter().skip(rendered.len()) {
+            edits.push(Edit {
+                start: slot.start,
+                remove: slot.end - slot.start + 1,
+                insert: Vec::new(),
+            });
+        }
+
+        let mut extra = Vec::new();
+        for block in rendered.iter().skip(existing.len()) {
+            if !extra.is_empty() || lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                extra.push(String::new());
+            }
+            extra.extend(render_block(name, "", &block.entries));
+        }
+        if !extra.is_empty() {
+            edits.push(Edit {
+                start: lines.len(),
+                remove: 0,
+                insert: extra,
+            });
+        }
+
+        edits.sort_by_key(|edit| std::cmp::Reverse(edit.start));
+        for edit in edits {
+            let delta = edit.insert.len() as isize - edit.remove as isize;
+            file_lines_mut(config, file).splice(edit.start..edit.start + edit.remove, edit.insert);
+            shift_sections(config, file, edit.start, delta);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(text: &str) -> HyprlandConfig {
+        let mut config = HyprlandConfig::new();
+        config.parse(text, false);
+        config
+    }
+
+    fn is_exec(keyword: &str) -> bool {
+        keyword == "exec-once"
+    }
+
+    fn execs(values: &[&str]) -> Vec<Directive> {
+        values
+            .iter()
+            .map(|value| Directive::new("exec-once", value, 0, None))
+            .collect()
+    }
+
+    #[test]
+    fn apply_reuses_existing_slots() {
+        let mut config =
+            config("exec-once = a\n\ngeneral {\n    gaps_in = 5\n}\n\n    exec-once = b\n");
+        apply(&mut config, &is_exec, &execs(&["x", "y"]));
+        assert_eq!(
+            config.content,
+            vec![
+                "exec-once = x",
+                "",
+                "general {",
+                "    gaps_in = 5",
+                "}",
+                "",
+                "    exec-once = y",
+            ]
+        );
+    }
+
+    #[test]
+    fn apply_keeps_unchanged_lines_verbatim() {
+        let mut config = config("exec-once = a # first\nexec-once = b\n");
+        let mut wanted = collect(&config, &is_exec);
+        wanted[1].value = "c".to_string();
+        apply(&mut config, &is_exec, &wanted);
+        assert_eq!(
+            config.content,
+            vec!["exec-once = a # first", "exec-once = c"]
+        );
+    }
+
+    #[test]
+    fn apply_removes_surplus_slots_and_appends_after_the_last() {
+        let mut config = config("exec-once = a\nexec-once = b\nmonitor = ,preferred,auto,1\n");
+        apply(&mut config, &is_exec, &execs(&["a"]));
+        assert_eq!(
+            config.content,
+            vec!["exec-once = a", "monitor = ,preferred,auto,1"]
+        );
+
+        apply(&mut config, &is_exec, &execs(&["a", "b", "c"]));
+        assert_eq!(
+            config.content,
+            vec![
+                "exec-once = a",
+                "exec-once = b",
+                "exec-once = c",
+                "monitor = ,preferred,auto,1",
+            ]
+        );
+    }
+
+    #[test]
+    fn options_are_found_in_one_liners() {
+        let mut config = config("general:gaps_in = 5 # mine\ngeneral {\n    border_size = 2\n}\n");
+        assert_eq!(find_option(&config, "general", "gaps_in"), Some((0, 0)));
+        assert_eq!(find_option(&config, "general", "border_size"), Some((0, 2)));
+
+        set_option(&mut config, 0, "general", "gaps_in", "8");
+        assert_eq!(config.content[0], "general:gaps_in = 8 # mine");
+
+        remove_option(&mut config, "general", "gaps_in");
+        assert_eq!(
+            config.content,
+            vec!["general {", "    border_size = 2", "}"]
+        );
+    }
+}
@@ -0,0 +1,384 @@
+use gtk::{
+    prelude::*, Box, Button, DropDown, Entry, Label, ListBox, Orientation, ScrolledWindow,
+    SelectionMode, StringList,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::widgets::WidgetBuilder;
+
+const ENV_KEYWORDS: [&str; 2] = ["env", "envd"];
+
+pub const ENV_PRESETS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "NVIDIA",
+        &[
+            ("LIBVA_DRIVER_NAME", "nvidia"),
+            ("__GLX_VENDOR_LIBRARY_NAME", "nvidia"),
+            ("GBM_BACKEND", "nvidia-drm"),
+            ("NVD_BACKEND", "direct"),
+            ("ELECTRON_OZONE_PLATFORM_HINT", "auto"),
+        ],
+    ),
+    (
+        "Qt",
+        &[
+            ("QT_QPA_PLATFORM", "wayland;xcb"),
+            ("QT_QPA_PLATFORMTHEME", "qt6ct"),
+            ("QT_WAYLAND_DISABLE_WINDOWDECORATION", "1"),
+            ("QT_AUTO_SCREEN_SCALE_FACTOR", "1"),
+        ],
+    ),
+    (
+        "GTK",
+        &[("GDK_BACKEND", "wayland,x11,*"), ("GDK_SCALE", "1")],
+    ),
+    (
+        "Cursor",
+        &[
+            ("XCURSOR_THEME", "Adwaita"),
+            ("XCURSOR_SIZE", "24"),
+            ("HYPRCURSOR_THEME", "Adwaita"),
+            ("HYPRCURSOR_SIZE", "24"),
+        ],
+    ),
+    (
+        "Toolkit Backends",
+        &[
+            ("SDL_VIDEODRIVER", "wayland"),
+            ("CLUTTER_BACKEND", "wayland"),
+        ],
+    ),
+    (
+        "XDG Session",
+        &[
+            ("XDG_CURRENT_DESKTOP", "Hyprland"),
+            ("XDG_SESSION_TYPE", "wayland"),
+            ("XDG_SESSION_DESKTOP", "Hyprland"),
+        ],
+    ),
+];
+
+fn is_env_keyword(keyword: &str) -> bool {
+    ENV_KEYWORDS.contains(&keyword)
+}
+
+pub fn split_env(value: &str) -> (String, String) {
+    let (key, value) = value.split_once(',').unwrap_or((value, ""));
+    (key.trim().to_string(), value.trim().to_string())
+}
+
+pub fn join_env(key: &str, value: &str) -> String {
+    format!("{},{}", key, value)
+}
+
+#[derive(Clone)]
+pub struct EnvironmentPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    variables: Rc<RefCell<Vec<Directive>>>,
+    key_entries: Rc<RefCell<Vec<(Entry, Label)>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl EnvironmentPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Environment",
+            "Variables are set in order, so later lines can expand earlier ones.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Variable");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Presets",
+            "Common variables for toolkits and drivers. Existing keys are updated in place.",
+            first_section.clone(),
+        );
+
+        let preset_box = Box::new(Orientation::Horizontal, 5);
+        preset_box.set_margin_top(10);
+        let preset_names: Vec<&str> = ENV_PRESETS.iter().map(|(name, _)| *name).collect();
+        let preset_dropdown = DropDown::new(
+            Some(StringList::new(&preset_names)),
+            None::<gtk::Expression>,
+        );
+        let preset_label = Label::new(None);
+        preset_label.set_opacity(0.7);
+        preset_label.set_hexpand(true);
+        preset_label.set_xalign(0.0);
+        preset_label.set_wrap(true);
+        let apply_preset_button = Button::with_label("Apply Preset");
+        preset_box.append(&preset_dropdown);
+        preset_box.append(&preset_label);
+        preset_box.append(&apply_preset_button);
+        container.append(&preset_box);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            variables: Rc::new(RefCell::new(Vec::new())),
+            key_entries: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            page_clone
+                .variables
+                .borrow_mut()
+                .push(Directive::new("env", ",", 0, None));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        let describe_preset = move |label: &Label, index: u32| {
+            if let Some((_, variables)) = ENV_PRESETS.get(index as usize) {
+                let text: Vec<String> = variables
+                    .iter()
+                    .map(|(key, value)| format!("{}={}", key, value))
+                    .collect();
+                label.set_text(&text.join("  "));
+            }
+        };
+        describe_preset(&preset_label, 0);
+        preset_dropdown.connect_selected_notify(move |dd| {
+            describe_preset(&preset_label, dd.selected());
+        });
+
+        let page_clone = page.clone();
+        apply_preset_button.connect_clicked(move |_| {
+            if let Some((_, variables)) = ENV_PRESETS.get(preset_dropdown.selected() as usize) {
+                page_clone.apply_preset(variables);
+            }
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.variables.borrow_mut() = directives::collect(config, &is_env_keyword);
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &is_env_keyword, &self.variables.borrow());
+        }
+    }
+
+    fn apply_preset(&self, preset: &[(&str, &str)]) {
+        {
+            let mut variables = self.variables.borrow_mut();
+            for (key, value) in preset {
+                let existing = variables
+                    .iter()
+                    .rposition(|d| d.submap.is_none() && split_env(&d.value).0 == *key);
+                match existing {
+                    Some(index) => variables[index].value = join_env(key, value),
+                    None => variables.push(Directive::new("env", &join_env(key, value), 0, None)),
+                }
+            }
+        }
+        *self.changed.borrow_mut() = true;
+        self.refresh();
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+        self.key_entries.borrow_mut().clear();
+
+        let count = self.variables.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+
+        self.refresh_duplicates();
+    }
+
+    fn refresh_duplicates(&self) {
+        let keys: Vec<String> = self
+            .variables
+            .borrow()
+            .iter()
+            .map(|d| split_env(&d.value).0)
+            .collect();
+
+        for (index, (entry, label)) in self.key_entries.borrow().iter().enumerate() {
+            let key = &keys[index];
+            let later = keys
+                .iter()
+                .enumerate()
+                .skip(index + 1)
+                .find(|(_, other)| !key.is_empty() && *other == key)
+                .map(|(i, _)| i);
+            let earlier = keys[..index]
+                .iter()
+                .rposition(|other| !key.is_empty() && other == key);
+
+            let text = match (earlier, later) {
+                (_, Some(later)) => format!("Overridden by row {}", later + 1),
+                (Some(earlier), None) => format!("Overrides row {}", earlier + 1),
+                (None, None) => String::new(),
+            };
+
+            if text.is_empty() {
+                entry.remove_css_class("warning");
+            } else {
+                entry.add_css_class("warning");
+            }
+            label.set_visible(!text.is_empty());
+            label.set_text(&text);
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut Directive)) {
+        if let Some(directive) = self.variables.borrow_mut().get_mut(index) {
+            edit(directive);
+        }
+        *self.changed.borrow_mut() = true;
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let directive = self.variables.borrow()[index].clone();
+        let (key, value) = split_env(&directive.value);
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+        hbox.set_margin_start(5);
+        hbox.set_margin_end(5);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let mut keywords: Vec<&str> = ENV_KEYWORDS.to_vec();
+        if !keywords.contains(&directive.keyword.as_str()) {
+            keywords.push(&directive.keyword);
+        }
+        let keyword_dropdown =
+            DropDown::new(Some(StringList::new(&keywords)), None::<gtk::Expression>);
+        if let Some(position) = keywords.iter().position(|k| *k == directive.keyword) {
+            keyword_dropdown.set_selected(position as u32);
+        }
+        keyword_dropdown.set_tooltip_text(Some("envd also exports the variable to D-Bus"));
+
+        let key_entry = Entry::new();
+        key_entry.set_placeholder_text(Some("Name"));
+        key_entry.set_text(&key);
+        key_entry.set_width_chars(24);
+
+        let value_entry = Entry::new();
+        value_entry.set_placeholder_text(Some("Value"));
+        value_entry.set_text(&value);
+        value_entry.set_hexpand(true);
+
+        let duplicate_label = Label::new(None);
+        duplicate_label.add_css_class("warning");
+        duplicate_label.set_visible(false);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.variables.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&keyword_dropdown);
+        hbox.append(&key_entry);
+        hbox.append(&value_entry);
+        hbox.append(&duplicate_label);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        self.key_entries
+            .borrow_mut()
+            .push((key_entry.clone(), duplicate_label));
+
+        let page = self.clone();
+        keyword_dropdown.connect_selected_notify(move |dd| {
+            let keyword = dd
+                .selected_item()
+                .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+                .map(|item| item.string().to_string())
+                .unwrap_or_default();
+            page.update(index, |d| d.keyword = keyword.clone());
+        });
+
+        let page = self.clone();
+        key_entry.connect_changed(move |entry| {
+            let key = entry.text().trim().to_string();
+            page.update(index, |d| {
+                let (_, value) = split_env(&d.value);
+                d.value = join_env(&key, &value);
+            });
+            page.refresh_duplicates();
+        });
+
+        let page = self.clone();
+        value_entry.connect_changed(move |entry| {
+            let value = entry.text().to_string();
+            page.update(index, |d| {
+                let (key, _) = split_env(&d.value);
+                d.value = join_env(&key, &value);
+            });
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        hbox
+    }
+}
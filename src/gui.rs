@@ -1,7 +1,7 @@
 use gtk::{
     gdk, glib, prelude::*, Application, ApplicationWindow, Box, Button, ColorButton, DropDown,
-    Entry, HeaderBar, Image, Label, MessageDialog, Orientation, Popover, ScrolledWindow,
-    SearchEntry, SpinButton, Stack, StackSidebar, StringList, Switch, Widget,
+    HeaderBar, Image, Label, MessageDialog, Orientation, Popover, ScrolledWindow, SearchEntry,
+    Stack, StackSidebar, StringList, Widget,
 };
 
 use hyprparser::HyprlandConfig;
@@ -11,7 +11,21 @@ use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
+use crate::animations::AnimationsPage;
+use crate::autostart::AutostartPage;
+use crate::binds::{render_cheatsheet, CheatSheetFormat};
+use crate::directives;
+use crate::environment::EnvironmentPage;
+use crate::history::History;
+use crate::keybinds::KeybindsPage;
+use crate::layerrules::LayerRulesPage;
 use crate::modules::ConfigWidget;
+use crate::monitors::MonitorsPage;
+use crate::variables::{self, VariablesPage};
+use crate::watcher::ConfigWatcher;
+use crate::widgets::WidgetBuilder;
+use crate::windowrules::WindowRulesPage;
+use crate::workspaces::WorkspacesPage;
 
 pub fn add_dropdown_option(
     container: &Box,
@@ -68,10 +82,20 @@ pub fn add_dropdown_option(
     options.insert(name.to_string(), dropdown.upcast());
 }
 
+pub fn option_path(category: &str, name: &str) -> (String, String) {
+    match name.split_once(':') {
+        Some((subsection, key)) if category == "layouts" => {
+            (subsection.to_string(), key.to_string())
+        }
+        Some((subsection, key)) => (format!("{}.{}", category, subsection), key.to_string()),
+        None => (category.to_string(), name.to_string()),
+    }
+}
+
 pub fn get_option_limits(name: &str, description: &str) -> (f64, f64, f64) {
     match name {
-        "border_size" => (0.0, 10.0, 1.0),
-        "gaps_in" | "gaps_out" | "gaps_workspaces" => (0.0, 50.0, 1.0),
+        "border_size" | "bordersize" => (0.0, 10.0, 1.0),
+        "gaps_in" | "gaps_out" | "gaps_workspaces" | "gapsin" | "gapsout" => (0.0, 50.0, 1.0),
         "resize_corner" => (0.0, 4.0, 1.0),
         "rounding" => (0.0, 20.0, 1.0),
         "active_opacity" | "inactive_opacity" | "fullscreen_opacity" => (0.0, 1.0, 0.1),
@@ -145,6 +169,218 @@ pub fn get_option_limits(name: &str, description: &str) -> (f64, f64, f64) {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DispatcherArg {
+    None,
+    Command,
+    Workspace,
+    WorkspaceWindow,
+    Direction,
+    Vec2,
+    Window,
+    OptionalWindow,
+    Int,
+    Text,
+    Choice(&'static [&'static str]),
+}
+
+pub const DISPATCHERS: &[(&str, DispatcherArg)] = &[
+    ("exec", DispatcherArg::Command),
+    ("execr", DispatcherArg::Command),
+    ("pass", DispatcherArg::Window),
+    ("sendshortcut", DispatcherArg::Text),
+    ("killactive", DispatcherArg::None),
+    ("forcekillactive", DispatcherArg::None),
+    ("closewindow", DispatcherArg::Window),
+    ("killwindow", DispatcherArg::Window),
+    ("signal", DispatcherArg::Int),
+    ("signalwindow", DispatcherArg::Text),
+    ("workspace", DispatcherArg::Workspace),
+    ("movetoworkspace", DispatcherArg::WorkspaceWindow),
+    ("movetoworkspacesilent", DispatcherArg::WorkspaceWindow),
+    ("togglefloating", DispatcherArg::OptionalWindow),
+    ("setfloating", DispatcherArg::OptionalWindow),
+    ("settiled", DispatcherArg::OptionalWindow),
+    ("fullscreen", DispatcherArg::Choice(&["", "0", "1", "2"])),
+    ("fullscreenstate", DispatcherArg::Text),
+    ("dpms", DispatcherArg::Choice(&["on", "off", "toggle"])),
+    ("pin", DispatcherArg::OptionalWindow),
+    ("movefocus", DispatcherArg::Direction),
+    ("movewindow", DispatcherArg::Direction),
+    ("swapwindow", DispatcherArg::Direction),
+    ("centerwindow", DispatcherArg::Choice(&["", "1"])),
+    ("resizeactive", DispatcherArg::Vec2),
+    ("moveactive", DispatcherArg::Vec2),
+    ("resizewindowpixel", DispatcherArg::Text),
+    ("movewindowpixel", DispatcherArg::Text),
+    (
+        "cyclenext",
+        DispatcherArg::Choice(&["", "prev", "tiled", "floating", "visible", "hist"]),
+    ),
+    ("swapnext", DispatcherArg::Choice(&["", "prev"])),
+    ("tagwindow", DispatcherArg::Text),
+    ("focuswindow", DispatcherArg::Window),
+    ("focusmonitor", DispatcherArg::Text),
+    ("splitratio", DispatcherArg::Text),
+    (
+        "movecursortocorner",
+        DispatcherArg::Choice(&["0", "1", "2", "3"]),
+    ),
+    ("movecursor", DispatcherArg::Vec2),
+    ("renameworkspace", DispatcherArg::Text),
+    ("exit", DispatcherArg::None),
+    ("forcerendererreload", DispatcherArg::None),
+    ("movecurrentworkspacetomonitor", DispatcherArg::Text),
+    ("focusworkspaceoncurrentmonitor", DispatcherArg::Workspace),
+    ("moveworkspacetomonitor", DispatcherArg::Text),
+    ("swapactiveworkspaces", DispatcherArg::Text),
+    ("bringactivetotop", DispatcherArg::None),
+    ("alterzorder", DispatcherArg::Choice(&["top", "bottom"])),
+    ("togglespecialworkspace", DispatcherArg::Text),
+    ("focusurgentorlast", DispatcherArg::None),
+    ("togglegroup", DispatcherArg::None),
+    ("changegroupactive", DispatcherArg::Choice(&["f", "b"])),
+    ("focuscurrentorlast", DispatcherArg::None),
+    (
+        "lockgroups",
+        DispatcherArg::Choice(&["lock", "unlock", "toggle"]),
+    ),
+    (
+        "lockactivegroup",
+        DispatcherArg::Choice(&["lock", "unlock", "toggle"]),
+    ),
+    ("moveintogroup", DispatcherArg::Direction),
+    ("moveoutofgroup", DispatcherArg::OptionalWindow),
+    ("movewindoworgroup", DispatcherArg::Direction),
+    ("movegroupwindow", DispatcherArg::Choice(&["f", "b"])),
+    (
+        "denywindowfromgroup",
+        DispatcherArg::Choice(&["on", "off", "toggle"]),
+    ),
+    (
+        "setignoregrouplock",
+        DispatcherArg::Choice(&["on", "off", "toggle"]),
+    ),
+    ("global", DispatcherArg::Text),
+    ("submap", DispatcherArg::Text),
+    ("event", DispatcherArg::Text),
+    ("setprop", DispatcherArg::Text),
+    ("toggleswallow", DispatcherArg::None),
+    ("pseudo", DispatcherArg::OptionalWindow),
+    ("togglesplit", DispatcherArg::None),
+    ("swapsplit", DispatcherArg::None),
+    ("layoutmsg", DispatcherArg::Text),
+    ("mouse", DispatcherArg::Text),
+];
+
+pub fn get_dispatcher_arg(name: &str) -> DispatcherArg {
+    DISPATCHERS
+        .iter()
+        .find(|(dispatcher, _)| *dispatcher == name)
+        .map(|(_, arg)| *arg)
+        .unwrap_or(DispatcherArg::Text)
+}
+
+impl DispatcherArg {
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            DispatcherArg::Command => "command",
+            DispatcherArg::Workspace => "1, +1, r-1, r~1, name:web, special:magic",
+            DispatcherArg::WorkspaceWindow => "1, r~1, special:magic or 2,class:^(kitty)$",
+            DispatcherArg::Direction => "l, r, u, d",
+            DispatcherArg::Vec2 => "x y, e.g. 10 -10 or exact 50% 50%",
+            DispatcherArg::Window | DispatcherArg::OptionalWindow => {
+                "class:^(kitty)$, title:..., pid:..."
+            }
+            DispatcherArg::Int => "number",
+            _ => "arguments",
+        }
+    }
+
+    pub fn is_valid(&self, args: &str) -> bool {
+        let args = args.trim();
+        if args.contains('$') {
+            return true;
+        }
+        match self {
+            DispatcherArg::None => args.is_empty(),
+            DispatcherArg::Command => !args.is_empty(),
+            DispatcherArg::Workspace => is_valid_workspace(args),
+            DispatcherArg::WorkspaceWindow => match args.split_once(',') {
+                Some((workspace, window)) => {
+                    is_valid_workspace(workspace.trim()) && is_valid_window(window.trim())
+                }
+                None => is_valid_workspace(args),
+            },
+            DispatcherArg::Direction => {
+                matches!(
+                    args,
+                    "l" | "r" | "u" | "d" | "left" | "right" | "up" | "down"
+                ) || args.strip_prefix("mon:").is_some_and(|m| !m.is_empty())
+            }
+            DispatcherArg::Vec2 => {
+                let values: Vec<&str> = args
+                    .strip_prefix("exact")
+                    .unwrap_or(args)
+                    .split_whitespace()
+                    .collect();
+                values.len() == 2
+                    && values
+                        .iter()
+                        .all(|v| v.trim_end_matches('%').parse::<f64>().is_ok())
+            }
+            DispatcherArg::Window => is_valid_window(args),
+            DispatcherArg::OptionalWindow => args.is_empty() || is_valid_window(args),
+            DispatcherArg::Int => args.parse::<i64>().is_ok(),
+            DispatcherArg::Text => true,
+            DispatcherArg::Choice(choices) => choices.contains(&args),
+        }
+    }
+}
+
+fn is_valid_workspace(arg: &str) -> bool {
+    if arg.parse::<i64>().is_ok() {
+        return true;
+    }
+    for prefix in ["r", "m", "e"] {
+        if let Some(offset) = arg.strip_prefix(prefix) {
+            if (offset.starts_with('+') || offset.starts_with('-') || offset.starts_with('~'))
+                && offset[1..].parse::<u32>().is_ok()
+            {
+                return true;
+            }
+        }
+    }
+    for prefix in ["name:", "special:"] {
+        if arg
+            .strip_prefix(prefix)
+            .is_some_and(|name| !name.is_empty())
+        {
+            return true;
+        }
+    }
+    matches!(
+        arg,
+        "previous" | "previous_per_monitor" | "special" | "empty" | "emptym" | "emptyn" | "emptynm"
+    )
+}
+
+fn is_valid_window(arg: &str) -> bool {
+    let prefixes = [
+        "class:",
+        "initialclass:",
+        "title:",
+        "initialtitle:",
+        "tag:",
+        "pid:",
+        "address:",
+    ];
+    prefixes
+        .iter()
+        .any(|prefix| arg.strip_prefix(prefix).is_some_and(|v| !v.is_empty()))
+        || matches!(arg, "activewindow" | "floating" | "tiled")
+}
+
 pub struct ConfigGUI {
     pub window: ApplicationWindow,
     pub config_widgets: HashMap<String, ConfigWidget>,
@@ -152,11 +388,25 @@ pub struct ConfigGUI {
     pub search_entry: SearchEntry,
     content_box: Box,
     changed_options: Rc<RefCell<HashMap<(String, String), String>>>,
+    history: History,
     stack: Stack,
     pub sidebar: StackSidebar,
     load_config_button: Button,
     save_config_button: Button,
+    export_cheatsheet_button: Button,
     pub gear_menu: Rc<RefCell<Popover>>,
+    keybinds: KeybindsPage,
+    monitors: MonitorsPage,
+    workspaces: WorkspacesPage,
+    window_rules: WindowRulesPage,
+    layer_rules: LayerRulesPage,
+    animations: AnimationsPage,
+    autostart: AutostartPage,
+    environment: EnvironmentPage,
+    variables: VariablesPage,
+    variable_values: Vec<(String, String)>,
+    target_file: DropDown,
+    pub watcher: ConfigWatcher,
 }
 
 impl ConfigGUI {
@@ -195,9 +445,24 @@ impl ConfigGUI {
 
         let save_config_button = Button::with_label("Save HyprGUI Config");
         let load_config_button = Button::with_label("Load HyprGUI Config");
+        let export_cheatsheet_button = Button::with_label("Export Cheat Sheet");
 
         gear_menu_box.append(&load_config_button);
         gear_menu_box.append(&save_config_button);
+        gear_menu_box.append(&export_cheatsheet_button);
+
+        let target_file_label = Label::new(Some("Write new options to"));
+        target_file_label.set_halign(gtk::Align::Start);
+        target_file_label.set_margin_top(5);
+        let target_file = DropDown::new(
+            Some(StringList::new(&["hyprland.conf"])),
+            None::<gtk::Expression>,
+        );
+        target_file.set_tooltip_text(Some(
+            "Options already defined somewhere are updated in the file that defines them",
+        ));
+        gear_menu_box.append(&target_file_label);
+        gear_menu_box.append(&target_file);
 
         gear_menu.borrow().set_child(Some(&gear_menu_box));
 
@@ -232,6 +497,42 @@ impl ConfigGUI {
         });
         search_entry.add_controller(key_controller);
 
+        let history = History::new();
+        let history_clone = history.clone();
+        let undo_controller = gtk::EventControllerKey::new();
+        undo_controller.set_propagation_phase(gtk::PropagationPhase::Capture);
+        undo_controller.connect_key_pressed(move |controller, key, _, modifiers| {
+            if !modifiers.contains(gdk::ModifierType::CONTROL_MASK) {
+                return glib::Propagation::Proceed;
+            }
+            let editing = controller
+                .widget()
+                .and_then(|widget| widget.root())
+                .and_then(|root| root.focus())
+                .is_some_and(|focus| {
+                    focus.is::<gtk::Text>()
+                        && focus.ancestor(gtk::SpinButton::static_type()).is_none()
+                        && focus.ancestor(gtk::DropDown::static_type()).is_none()
+                });
+            if editing {
+                return glib::Propagation::Proceed;
+            }
+            let handled = match key {
+                gdk::Key::z if modifiers.contains(gdk::ModifierType::SHIFT_MASK) => {
+                    history_clone.redo()
+                }
+                gdk::Key::Z => history_clone.redo(),
+                gdk::Key::z => history_clone.undo(),
+                _ => return glib::Propagation::Proceed,
+            };
+            if handled {
+                glib::Propagation::Stop
+            } else {
+                glib::Propagation::Proceed
+            }
+        });
+        window.add_controller(undo_controller);
+
         header_bar.pack_start(&search_button);
 
         let save_button = Button::with_label("Save");
@@ -241,6 +542,9 @@ impl ConfigGUI {
 
         let main_box = Box::new(Orientation::Vertical, 0);
 
+        let watcher = ConfigWatcher::new();
+        main_box.append(&watcher.revealer);
+
         let content_box = Box::new(Orientation::Horizontal, 0);
         main_box.append(&content_box);
 
@@ -261,11 +565,25 @@ impl ConfigGUI {
             search_entry,
             content_box,
             changed_options: Rc::new(RefCell::new(HashMap::new())),
+            history,
             stack,
             sidebar,
             load_config_button,
             save_config_button,
+            export_cheatsheet_button,
             gear_menu,
+            keybinds: KeybindsPage::new(),
+            monitors: MonitorsPage::new(),
+            workspaces: WorkspacesPage::new(),
+            window_rules: WindowRulesPage::new(),
+            layer_rules: LayerRulesPage::new(),
+            animations: AnimationsPage::new(),
+            autostart: AutostartPage::new(),
+            environment: EnvironmentPage::new(),
+            variables: VariablesPage::new(),
+            variable_values: Vec::new(),
+            target_file,
+            watcher,
         }
     }
 
@@ -337,6 +655,56 @@ impl ConfigGUI {
                     file_chooser.close();
                 });
             });
+
+        let gui_clone = Rc::clone(&gui);
+        gui.borrow()
+            .export_cheatsheet_button
+            .connect_clicked(move |button| {
+                if let Some(popover) = button.ancestor(gtk::Popover::static_type()) {
+                    if let Some(popover) = popover.downcast_ref::<gtk::Popover>() {
+                        popover.popdown();
+                    }
+                }
+
+                let gui = Rc::clone(&gui_clone);
+                glib::MainContext::default().spawn_local(async move {
+                    let file_chooser = gtk::FileChooserDialog::new(
+                        Some("Export Cheat Sheet"),
+                        Some(&gui.borrow().window),
+                        gtk::FileChooserAction::Save,
+                        &[
+                            ("Cancel", gtk::ResponseType::Cancel),
+                            ("Export", gtk::ResponseType::Accept),
+                        ],
+                    );
+
+                    file_chooser.add_choice(
+                        "format",
+                        "Format",
+                        &[
+                            ("markdown", "Markdown"),
+                            ("html", "HTML"),
+                            ("text", "Plain text"),
+                        ],
+                    );
+                    file_chooser.set_choice("format", "markdown");
+                    file_chooser.set_current_name("hyprland_keybindings.md");
+
+                    if file_chooser.run_future().await == gtk::ResponseType::Accept {
+                        let format = match file_chooser.choice("format").as_deref() {
+                            Some("html") => CheatSheetFormat::Html,
+                            Some("text") => CheatSheetFormat::Text,
+                            _ => CheatSheetFormat::Markdown,
+                        };
+                        if let Some(file) = file_chooser.file() {
+                            if let Some(path) = file.path() {
+                                gui.borrow_mut().export_cheatsheet(&path, format);
+                            }
+                        }
+                    }
+                    file_chooser.close();
+                });
+            });
     }
 
     fn load_hyprgui_config(&mut self, path: &PathBuf) {
@@ -416,35 +784,32 @@ impl ConfigGUI {
         }
     }
 
-    fn set_widget_value(&self, widget: &Widget, value: &str) {
-        if let Some(spin_button) = widget.downcast_ref::<SpinButton>() {
-            if let Ok(float_value) = value.parse::<f64>() {
-                spin_button.set_value(float_value);
-            }
-        } else if let Some(entry) = widget.downcast_ref::<Entry>() {
-            entry.set_text(value);
-        } else if let Some(switch) = widget.downcast_ref::<Switch>() {
-            switch.set_active(value == "true");
-        } else if let Some(color_button) = widget.downcast_ref::<ColorButton>() {
-            let dummy_config = HyprlandConfig::new();
-            if let Some((red, green, blue, alpha)) = dummy_config.parse_color(value) {
-                color_button.set_rgba(&gdk::RGBA::new(red, green, blue, alpha));
+    fn export_cheatsheet(&mut self, path: &PathBuf, format: CheatSheetFormat) {
+        let cheatsheet = render_cheatsheet(&self.keybinds.bind_list(), format);
+        match fs::write(path, cheatsheet) {
+            Ok(_) => {
+                self.custom_info_popup(
+                    "Cheat Sheet Exported",
+                    "Keybinding cheat sheet exported successfully.",
+                    false,
+                );
             }
-        } else if let Some(dropdown) = widget.downcast_ref::<DropDown>() {
-            let model = dropdown.model().unwrap();
-            for i in 0..model.n_items() {
-                if let Some(item) = model.item(i) {
-                    if let Some(string_object) = item.downcast_ref::<gtk::StringObject>() {
-                        if string_object.string() == value {
-                            dropdown.set_selected(i);
-                            break;
-                        }
-                    }
-                }
+            Err(e) => {
+                self.custom_error_popup(
+                    "Export Failed",
+                    &format!("Failed to write the cheat sheet: {}", e),
+                    false,
+                );
             }
         }
     }
 
+    fn set_widget_value(&self, widget: &Widget, value: &str) {
+        let evaluated = variables::evaluate_expressions(value, &self.variable_values)
+            .unwrap_or_else(|_| value.to_string());
+        WidgetBuilder::set_value(widget, &evaluated);
+    }
+
     pub fn custom_info_popup(&mut self, title: &str, text: &str, modal: bool) {
         let dialog = MessageDialog::builder()
             .message_type(gtk::MessageType::Info)
@@ -495,6 +860,17 @@ impl ConfigGUI {
 
     pub fn load_config(&mut self, config: &HyprlandConfig) {
         self.config_widgets.clear();
+        self.history.clear();
+        self.variable_values = variables::collect_values(config);
+
+        let selected_file = self.target_file.selected();
+        let files = directives::file_labels(config);
+        let file_labels: Vec<&str> = files.iter().map(String::as_str).collect();
+        self.target_file
+            .set_model(Some(&StringList::new(&file_labels)));
+        if (selected_file as usize) < files.len() {
+            self.target_file.set_selected(selected_file);
+        }
         self.content_box.set_visible(true);
 
         while let Some(child) = self.stack.first_child() {
@@ -547,10 +923,81 @@ impl ConfigGUI {
 
         for (_, category) in &categories {
             if let Some(widget) = self.config_widgets.get(*category) {
-                widget.load_config(config, category, self.changed_options.clone());
+                widget.load_config(
+                    config,
+                    category,
+                    self.changed_options.clone(),
+                    self.history.clone(),
+                );
             }
         }
 
+        self.keybinds = KeybindsPage::new();
+        self.stack
+            .add_titled(&self.keybinds.scrolled_window, Some("keybinds"), "Keybinds");
+        self.keybinds.load_config(config);
+
+        self.monitors = MonitorsPage::new();
+        self.stack
+            .add_titled(&self.monitors.scrolled_window, Some("monitors"), "Monitors");
+        self.monitors.load_config(config);
+
+        self.workspaces = WorkspacesPage::new();
+        self.stack.add_titled(
+            &self.workspaces.scrolled_window,
+            Some("workspaces"),
+            "Workspaces",
+        );
+        self.workspaces.load_config(config);
+
+        self.window_rules = WindowRulesPage::new();
+        self.stack.add_titled(
+            &self.window_rules.scrolled_window,
+            Some("window_rules"),
+            "Window Rules",
+        );
+        self.window_rules.load_config(config);
+
+        self.layer_rules = LayerRulesPage::new();
+        self.stack.add_titled(
+            &self.layer_rules.scrolled_window,
+            Some("layer_rules"),
+            "Layer Rules",
+        );
+        self.layer_rules.load_config(config);
+
+        self.animations = AnimationsPage::new();
+        self.stack.add_titled(
+            &self.animations.scrolled_window,
+            Some("animation_tree"),
+            "Animation Tree",
+        );
+        self.animations.load_config(config);
+
+        self.autostart = AutostartPage::new();
+        self.stack.add_titled(
+            &self.autostart.scrolled_window,
+            Some("autostart"),
+            "Autostart",
+        );
+        self.autostart.load_config(config);
+
+        self.environment = EnvironmentPage::new();
+        self.stack.add_titled(
+            &self.environment.scrolled_window,
+            Some("environment"),
+            "Environment",
+        );
+        self.environment.load_config(config);
+
+        self.variables = VariablesPage::new();
+        self.stack.add_titled(
+            &self.variables.scrolled_window,
+            Some("variables"),
+            "Variables",
+        );
+        self.variables.load_config(config);
+
         self.changed_options.borrow_mut().clear();
     }
 
@@ -558,6 +1005,31 @@ impl ConfigGUI {
         self.changed_options.clone()
     }
 
+    pub fn invalid_keybinds(&self) -> Vec<String> {
+        self.keybinds.invalid_binds()
+    }
+
+    pub fn invalid_variables(&self) -> Vec<String> {
+        self.variables.invalid_variables()
+    }
+
+    pub fn invalid_layer_rules(&self) -> Vec<String> {
+        self.layer_rules.invalid_rules()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.changed_options.borrow().is_empty()
+            || self.keybinds.has_changes()
+            || self.monitors.has_changes()
+            || self.workspaces.has_changes()
+            || self.window_rules.has_changes()
+            || self.layer_rules.has_changes()
+            || self.animations.has_changes()
+            || self.autostart.has_changes()
+            || self.environment.has_changes()
+            || self.variables.has_changes()
+    }
+
     pub fn apply_changes(&self, config: &mut HyprlandConfig) {
         let changes = self.changed_options.borrow();
         for (category, widget) in &self.config_widgets {
@@ -577,29 +1049,30 @@ impl ConfigGUI {
                             value.clone()
                         };
 
-                    if !formatted_value.is_empty() {
-                        if category == "layouts" {
-                            let parts: Vec<&str> = name.split(':').collect();
-                            if parts.len() == 2 {
-                                config.add_entry(
-                                    parts[0],
-                                    &format!("{} = {}", parts[1], formatted_value),
-                                );
-                            }
-                        } else if name.contains(':') {
-                            let parts: Vec<&str> = name.split(':').collect();
-                            if parts.len() == 2 {
-                                config.add_entry(
-                                    &format!("{}.{}", category, parts[0]),
-                                    &format!("{} = {}", parts[1], formatted_value),
-                                );
-                            }
-                        } else {
-                            config.add_entry(category, &format!("{} = {}", name, formatted_value));
-                        }
+                    let (section, key) = option_path(category, name);
+                    if value.is_empty() {
+                        directives::remove_option(config, &section, &key);
+                    } else {
+                        directives::set_option(
+                            config,
+                            self.target_file.selected() as usize,
+                            &section,
+                            &key,
+                            &formatted_value,
+                        );
                     }
                 }
             }
         }
+
+        self.keybinds.apply_changes(config);
+        self.monitors.apply_changes(config);
+        self.workspaces.apply_changes(config);
+        self.window_rules.apply_changes(config);
+        self.layer_rules.apply_changes(config);
+        self.animations.apply_changes(config);
+        self.autostart.apply_changes(config);
+        self.environment.apply_changes(config);
+        self.variables.apply_changes(config);
     }
 }
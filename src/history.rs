@@ -0,0 +1,219 @@
+use gtk::glib;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+const MERGE_WINDOW_US: i64 = 1_000_000;
+
+type Restorer = Rc<dyn Fn(&str, Option<String>)>;
+
+#[derive(Clone)]
+struct Change {
+    key: (String, String),
+    before: Option<String>,
+    after: Option<String>,
+    shown_before: String,
+    shown_after: String,
+    time: i64,
+}
+
+#[derive(Clone, Default)]
+pub struct History {
+    undo: Rc<RefCell<Vec<Change>>>,
+    redo: Rc<RefCell<Vec<Change>>>,
+    suppressed: Rc<Cell<bool>>,
+    restorers: Rc<RefCell<HashMap<(String, String), Restorer>>>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clear(&self) {
+        self.undo.borrow_mut().clear();
+        self.redo.borrow_mut().clear();
+        self.restorers.borrow_mut().clear();
+    }
+
+    pub fn register(
+        &self,
+        key: (String, String),
+        restore: impl Fn(&str, Option<String>) + 'static,
+    ) {
+        self.restorers.borrow_mut().insert(key, Rc::new(restore));
+    }
+
+    pub fn suppress(&self, f: impl FnOnce()) {
+        let previous = self.suppressed.replace(true);
+        f();
+        self.suppressed.set(previous);
+    }
+
+    pub fn push(
+        &self,
+        key: (String, String),
+        before: Option<String>,
+        after: Option<String>,
+        shown_before: String,
+        shown_after: String,
+    ) {
+        if self.suppressed.get() || (before == after && shown_before == shown_after) {
+            return;
+        }
+        self.record(Change {
+            key,
+            before,
+            after,
+            shown_before,
+            shown_after,
+            time: glib::monotonic_time(),
+        });
+    }
+
+    fn record(&self, change: Change) {
+        let mut redo = self.redo.borrow_mut();
+        let mergeable = redo.is_empty();
+        redo.clear();
+
+        let mut undo = self.undo.borrow_mut();
+        if let Some(last) = undo.last_mut() {
+            if mergeable && last.key == change.key && change.time - last.time < MERGE_WINDOW_US {
+                last.after = change.after;
+                last.shown_after = change.shown_after;
+                last.time = change.time;
+                return;
+            }
+        }
+        undo.push(change);
+    }
+
+    fn replay(&self, change: &Change, undo: bool) {
+        let restorer = self.restorers.borrow().get(&change.key).cloned();
+        if let Some(restore) = restorer {
+            let (shown, value) = if undo {
+                (&change.shown_before, change.before.clone())
+            } else {
+                (&change.shown_after, change.after.clone())
+            };
+            self.suppress(|| restore(shown, value));
+        }
+    }
+
+    pub fn undo(&self) -> bool {
+        let change = self.undo.borrow_mut().pop();
+        match change {
+            Some(change) => {
+                self.replay(&change, true);
+                self.redo.borrow_mut().push(change);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn redo(&self) -> bool {
+        let change = self.redo.borrow_mut().pop();
+        match change {
+            Some(change) => {
+                self.replay(&change, false);
+                self.undo.borrow_mut().push(change);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(value: &str, before: &str, time: i64) -> Change {
+        Change {
+            key: ("general".to_string(), "border_size".to_string()),
+            before: Some(before.to_string()),
+            after: Some(value.to_string()),
+            shown_before: before.to_string(),
+            shown_after: value.to_string(),
+            time,
+        }
+    }
+
+    fn tracked(history: &History) -> Rc<RefCell<Vec<String>>> {
+        let restored = Rc::new(RefCell::new(Vec::new()));
+        let log = restored.clone();
+        history.register(
+            ("general".to_string(), "border_size".to_string()),
+            move |shown, _| log.borrow_mut().push(shown.to_string()),
+        );
+        restored
+    }
+
+    #[test]
+    fn edits_within_the_merge_window_undo_together() {
+        let history = History::new();
+        let restored = tracked(&history);
+        history.record(change("2", "1", 0));
+        history.record(change("3", "2", MERGE_WINDOW_US - 1));
+
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(*restored.borrow(), vec!["1"]);
+    }
+
+    #[test]
+    fn edits_outside_the_merge_window_undo_separately() {
+        let history = History::new();
+        let restored = tracked(&history);
+        history.record(change("2", "1", 0));
+        history.record(change("3", "2", MERGE_WINDOW_US));
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert_eq!(*restored.borrow(), vec!["2", "1"]);
+    }
+
+    #[test]
+    fn new_edit_clears_redo_and_does_not_merge() {
+        let history = History::new();
+        let restored = tracked(&history);
+        history.record(change("2", "1", 0));
+        history.record(change("3", "2", MERGE_WINDOW_US));
+        assert!(history.undo());
+
+        history.record(change("4", "2", MERGE_WINDOW_US + 1));
+        assert!(!history.redo());
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert_eq!(*restored.borrow(), vec!["2", "2", "1"]);
+    }
+
+    #[test]
+    fn redo_replays_the_undone_value() {
+        let history = History::new();
+        let restored = tracked(&history);
+        history.record(change("2", "1", 0));
+
+        assert!(history.undo());
+        assert!(history.redo());
+        assert!(!history.redo());
+        assert_eq!(*restored.borrow(), vec!["1", "2"]);
+    }
+
+    #[test]
+    fn suppressed_pushes_are_ignored() {
+        let history = History::new();
+        history.suppress(|| {
+            history.push(
+                ("general".to_string(), "border_size".to_string()),
+                None,
+                Some("2".to_string()),
+                String::new(),
+                "2".to_string(),
+            )
+        });
+        assert!(!history.undo());
+    }
+}
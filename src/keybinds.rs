@@ -0,0 +1,863 @@
+use gtk::{
+    gdk, glib, prelude::*, Box, Button, CheckButton, DropDown, Entry, EventControllerKey, FlowBox,
+    Label, ListBox, Orientation, Popover, ScrolledWindow, SelectionMode, SpinButton, StringList,
+    Widget,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::binds::{combo_label, find_conflicts, is_bind_keyword, Bind, BIND_KEYWORDS};
+use crate::directives::{self, Directive};
+use crate::gui::{get_dispatcher_arg, DispatcherArg, DISPATCHERS};
+use crate::widgets::WidgetBuilder;
+
+#[derive(Clone)]
+pub struct KeybindsPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    conflicts: ListBox,
+    submaps_list: ListBox,
+    submaps: Rc<RefCell<Vec<String>>>,
+    binds: Rc<RefCell<Vec<Directive>>>,
+    loaded: Rc<RefCell<Vec<(String, String)>>>,
+    files: Rc<RefCell<Vec<String>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl KeybindsPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Keybindings",
+            "Add, edit, reorder and delete bind lines.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::Single);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Keybinding");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Submaps",
+            "Keybinding modes entered with the submap dispatcher.",
+            first_section.clone(),
+        );
+
+        let submaps_list = ListBox::new();
+        submaps_list.set_selection_mode(SelectionMode::None);
+        container.append(&submaps_list);
+
+        let new_submap_box = Box::new(Orientation::Horizontal, 5);
+        new_submap_box.set_margin_top(10);
+
+        let submap_name_entry = Entry::new();
+        submap_name_entry.set_placeholder_text(Some("Submap name"));
+        let enter_mods_entry = Entry::new();
+        enter_mods_entry.set_placeholder_text(Some("Enter modifiers"));
+        enter_mods_entry.set_text("SUPER");
+        let enter_key_entry = Entry::new();
+        enter_key_entry.set_placeholder_text(Some("Enter key"));
+        let escape_key_entry = Entry::new();
+        escape_key_entry.set_placeholder_text(Some("Escape key"));
+        escape_key_entry.set_text("escape");
+        let create_submap_button = Button::with_label("Create Submap");
+
+        new_submap_box.append(&submap_name_entry);
+        new_submap_box.append(&enter_mods_entry);
+        new_submap_box.append(&enter_key_entry);
+        new_submap_box.append(&escape_key_entry);
+        new_submap_box.append(&create_submap_button);
+        container.append(&new_submap_box);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Conflicts",
+            "Duplicate or colliding key combinations across all files.",
+            first_section.clone(),
+        );
+
+        let conflicts = ListBox::new();
+        conflicts.set_selection_mode(SelectionMode::None);
+        container.append(&conflicts);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            conflicts,
+            submaps_list,
+            submaps: Rc::new(RefCell::new(Vec::new())),
+            binds: Rc::new(RefCell::new(Vec::new())),
+            loaded: Rc::new(RefCell::new(Vec::new())),
+            files: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let bind = Bind {
+                mods: "SUPER".to_string(),
+                dispatcher: "exec".to_string(),
+                ..Bind::default()
+            };
+            page_clone.binds.borrow_mut().push(Directive::new(
+                &bind.keyword(),
+                &bind.value(),
+                0,
+                None,
+            ));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        let page_clone = page.clone();
+        create_submap_button.connect_clicked(move |_| {
+            let name = submap_name_entry.text().trim().to_string();
+            let key = enter_key_entry.text().trim().to_string();
+            if name.is_empty()
+                || name == "reset"
+                || key.is_empty()
+                || page_clone.submaps.borrow().contains(&name)
+            {
+                submap_name_entry.add_css_class("error");
+                return;
+            }
+            submap_name_entry.remove_css_class("error");
+
+            let enter_bind = Bind {
+                mods: enter_mods_entry.text().trim().to_string(),
+                key,
+                dispatcher: "submap".to_string(),
+                args: name.clone(),
+                ..Bind::default()
+            };
+            let escape_bind = Bind {
+                key: escape_key_entry.text().trim().to_string(),
+                dispatcher: "submap".to_string(),
+                args: "reset".to_string(),
+                ..Bind::default()
+            };
+
+            {
+                let mut binds = page_clone.binds.borrow_mut();
+                binds.push(Directive::new(
+                    &enter_bind.keyword(),
+                    &enter_bind.value(),
+                    0,
+                    None,
+                ));
+                binds.push(Directive::new(
+                    &escape_bind.keyword(),
+                    &escape_bind.value(),
+                    0,
+                    Some(name.clone()),
+                ));
+            }
+            page_clone.submaps.borrow_mut().push(name);
+            submap_name_entry.set_text("");
+            enter_key_entry.set_text("");
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.binds.borrow_mut() = directives::collect(config, &is_bind_keyword);
+        *self.loaded.borrow_mut() = self
+            .binds
+            .borrow()
+            .iter()
+            .map(|d| (d.keyword.clone(), d.value.clone()))
+            .collect();
+        *self.files.borrow_mut() = directives::file_labels(config);
+        *self.submaps.borrow_mut() = directives::submap_names(config);
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &is_bind_keyword, &self.binds.borrow());
+        }
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+
+        let count = self.binds.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+
+        self.refresh_conflicts();
+        self.refresh_submaps();
+    }
+
+    pub fn bind_list(&self) -> Vec<(Option<String>, Bind)> {
+        self.binds
+            .borrow()
+            .iter()
+            .map(|directive| {
+                (
+                    directive.submap.clone(),
+                    Bind::parse(&directive.keyword, &directive.value).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+
+    fn refresh_conflicts(&self) {
+        while let Some(row) = self.conflicts.first_child() {
+            self.conflicts.remove(&row);
+        }
+
+        let binds = self.bind_list();
+        let conflicts = find_conflicts(&binds);
+
+        if conflicts.is_empty() {
+            let label = Label::new(Some("No conflicts found."));
+            label.set_halign(gtk::Align::Start);
+            label.set_opacity(0.7);
+            label.set_margin_top(5);
+            label.set_margin_bottom(5);
+            label.set_margin_start(5);
+            self.conflicts.append(&label);
+            return;
+        }
+
+        for conflict in conflicts {
+            let hbox = Box::new(Orientation::Horizontal, 5);
+            hbox.set_margin_start(5);
+            hbox.set_margin_end(5);
+            hbox.set_margin_top(5);
+            hbox.set_margin_bottom(5);
+
+            let (submap, bind) = &binds[conflict.first];
+            let text = match submap {
+                Some(submap) => format!(
+                    "{}: {} (submap {})",
+                    conflict.kind.label(),
+                    combo_label(bind),
+                    submap
+                ),
+                None => format!("{}: {}", conflict.kind.label(), combo_label(bind)),
+            };
+            let label = Label::new(Some(&text));
+            label.set_halign(gtk::Align::Start);
+            label.set_hexpand(true);
+            hbox.append(&label);
+
+            for index in [conflict.first, conflict.second] {
+                let jump_button = Button::with_label(&format!("Jump to {}", self.location(index)));
+                let page = self.clone();
+                jump_button.connect_clicked(move |_| {
+                    page.jump_to(index);
+                });
+                hbox.append(&jump_button);
+            }
+
+            self.conflicts.append(&hbox);
+        }
+    }
+
+    fn refresh_submaps(&self) {
+        while let Some(row) = self.submaps_list.first_child() {
+            self.submaps_list.remove(&row);
+        }
+
+        let binds = self.bind_list();
+        let mut names = self.submaps.borrow().clone();
+        for (submap, _) in &binds {
+            if let Some(submap) = submap {
+                if !names.contains(submap) {
+                    names.push(submap.clone());
+                }
+            }
+        }
+
+        if names.is_empty() {
+            let label = Label::new(Some("No submaps defined."));
+            label.set_halign(gtk::Align::Start);
+            label.set_opacity(0.7);
+            label.set_margin_top(5);
+            label.set_margin_bottom(5);
+            label.set_margin_start(5);
+            self.submaps_list.append(&label);
+            return;
+        }
+
+        for name in names {
+            let vbox = Box::new(Orientation::Vertical, 2);
+            vbox.set_margin_start(5);
+            vbox.set_margin_end(5);
+            vbox.set_margin_top(5);
+            vbox.set_margin_bottom(5);
+
+            let title_label = Label::new(None);
+            title_label.set_markup(&format!("<b>{}</b>", glib::markup_escape_text(&name)));
+            title_label.set_halign(gtk::Align::Start);
+            vbox.append(&title_label);
+
+            let members: Vec<usize> = binds
+                .iter()
+                .enumerate()
+                .filter(|(_, (submap, _))| submap.as_ref() == Some(&name))
+                .map(|(i, _)| i)
+                .collect();
+            let entries: Vec<usize> = binds
+                .iter()
+                .enumerate()
+                .filter(|(_, (submap, bind))| {
+                    submap.as_ref() != Some(&name)
+                        && bind.dispatcher == "submap"
+                        && bind.args == name
+                })
+                .map(|(i, _)| i)
+                .collect();
+            let escapes: Vec<usize> = members
+                .iter()
+                .copied()
+                .filter(|i| binds[*i].1.dispatcher == "submap")
+                .collect();
+
+            for (title, indices) in [
+                ("Entered by", &entries),
+                ("Escaped by", &escapes),
+                ("Binds", &members),
+            ] {
+                let hbox = Box::new(Orientation::Horizontal, 5);
+                let label = Label::new(Some(&format!("{}:", title)));
+                label.set_opacity(0.7);
+                label.set_valign(gtk::Align::Start);
+                label.set_margin_top(5);
+                hbox.append(&label);
+
+                let flow_box = FlowBox::new();
+                flow_box.set_selection_mode(SelectionMode::None);
+                flow_box.set_hexpand(true);
+                hbox.append(&flow_box);
+
+                if indices.is_empty() {
+                    let none_label = Label::new(Some("none"));
+                    none_label.set_opacity(0.7);
+                    flow_box.insert(&none_label, -1);
+                }
+                for index in indices.iter().copied() {
+                    let bind = &binds[index].1;
+                    let text = if title == "Binds" {
+                        format!("{} → {} {}", combo_label(bind), bind.dispatcher, bind.args)
+                    } else {
+                        combo_label(bind)
+                    };
+                    let jump_button = Button::with_label(text.trim());
+                    jump_button.set_has_frame(false);
+                    let page = self.clone();
+                    jump_button.connect_clicked(move |_| {
+                        page.jump_to(index);
+                    });
+                    flow_box.insert(&jump_button, -1);
+                }
+                vbox.append(&hbox);
+            }
+
+            self.submaps_list.append(&vbox);
+        }
+    }
+
+    fn location(&self, index: usize) -> String {
+        let binds = self.binds.borrow();
+        let files = self.files.borrow();
+        let directive = &binds[index];
+        let file = files.get(directive.file).cloned().unwrap_or_default();
+        match directive.line {
+            Some(line) => format!("{}:{}", file, line + 1),
+            None => format!("{} (unsaved)", file),
+        }
+    }
+
+    fn jump_to(&self, index: usize) {
+        if let Some(row) = self.list.row_at_index(index as i32) {
+            self.list.select_row(Some(&row));
+            if let Some((_, y)) = row.translate_coordinates(&self.scrolled_window, 0.0, 0.0) {
+                let adj = self.scrolled_window.vadjustment();
+                adj.set_value((adj.value() + y - 20.0).clamp(adj.lower(), adj.upper()));
+            }
+            row.grab_focus();
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut Bind)) {
+        if let Some(directive) = self.binds.borrow_mut().get_mut(index) {
+            let mut bind = Bind::parse(&directive.keyword, &directive.value).unwrap_or_default();
+            edit(&mut bind);
+            directive.keyword = bind.keyword();
+            directive.value = bind.value();
+        }
+        *self.changed.borrow_mut() = true;
+        self.refresh_conflicts();
+        self.refresh_submaps();
+    }
+
+    pub fn invalid_binds(&self) -> Vec<String> {
+        if !self.has_changes() {
+            return Vec::new();
+        }
+        let loaded = self.loaded.borrow();
+        self.binds
+            .borrow()
+            .iter()
+            .filter(|directive| {
+                !loaded.iter().any(|(keyword, value)| {
+                    *keyword == directive.keyword && *value == directive.value
+                })
+            })
+            .filter_map(|directive| {
+                let bind = Bind::parse(&directive.keyword, &directive.value)?;
+                let mouse_drag = bind.flags.contains('m')
+                    && bind.args.trim().is_empty()
+                    && matches!(bind.dispatcher.as_str(), "movewindow" | "resizewindow");
+                if mouse_drag || get_dispatcher_arg(&bind.dispatcher).is_valid(&bind.args) {
+                    None
+                } else {
+                    Some(format!("{} = {}", directive.keyword, directive.value))
+                }
+            })
+            .collect()
+    }
+
+    fn build_args_widget(&self, index: usize, dispatcher: &str, args: &str) -> Widget {
+        let kind = get_dispatcher_arg(dispatcher);
+
+        let choices: Option<Vec<&str>> = match kind {
+            DispatcherArg::Choice(choices) => Some(choices.to_vec()),
+            DispatcherArg::Direction => Some(vec!["l", "r", "u", "d"]),
+            _ => None,
+        };
+        if let Some(choices) = choices.filter(|c| c.contains(&args)) {
+            let dropdown = DropDown::new(Some(StringList::new(&choices)), None::<gtk::Expression>);
+            if let Some(position) = choices.iter().position(|c| *c == args) {
+                dropdown.set_selected(position as u32);
+            }
+            let page = self.clone();
+            dropdown.connect_selected_notify(move |dd| {
+                if let Some(selected) = dd.selected_item() {
+                    if let Some(string_object) = selected.downcast_ref::<gtk::StringObject>() {
+                        let text = string_object.string().to_string();
+                        page.update(index, |bind| bind.args = text.clone());
+                    }
+                }
+            });
+            return dropdown.upcast();
+        }
+
+        match kind {
+            DispatcherArg::None if args.is_empty() => {
+                let label = Label::new(Some("no arguments"));
+                label.set_opacity(0.7);
+                label.set_halign(gtk::Align::Start);
+                return label.upcast();
+            }
+            DispatcherArg::Int => {
+                if let Ok(value) = args.parse::<f64>() {
+                    let spin_button = SpinButton::with_range(-1000.0, 1000.0, 1.0);
+                    spin_button.set_digits(0);
+                    spin_button.set_value(value);
+                    let page = self.clone();
+                    spin_button.connect_value_changed(move |sb| {
+                        let text = (sb.value() as i64).to_string();
+                        page.update(index, |bind| bind.args = text.clone());
+                    });
+                    return spin_button.upcast();
+                }
+            }
+            DispatcherArg::Vec2 => {
+                let exact = args.starts_with("exact");
+                let values: Vec<f64> = args
+                    .trim_start_matches("exact")
+                    .split_whitespace()
+                    .filter_map(|v| v.parse::<f64>().ok())
+                    .collect();
+                if values.len() == 2 {
+                    let vec2_box = Box::new(Orientation::Horizontal, 5);
+                    let exact_check = CheckButton::with_label("exact");
+                    exact_check.set_active(exact);
+                    let x_spin = SpinButton::with_range(-10000.0, 10000.0, 1.0);
+                    x_spin.set_digits(0);
+                    x_spin.set_value(values[0]);
+                    let y_spin = SpinButton::with_range(-10000.0, 10000.0, 1.0);
+                    y_spin.set_digits(0);
+                    y_spin.set_value(values[1]);
+                    vec2_box.append(&exact_check);
+                    vec2_box.append(&x_spin);
+                    vec2_box.append(&y_spin);
+
+                    let write_vec2 = {
+                        let page = self.clone();
+                        let exact_check = exact_check.clone();
+                        let x_spin = x_spin.clone();
+                        let y_spin = y_spin.clone();
+                        Rc::new(move || {
+                            let text = format!(
+                                "{}{} {}",
+                                if exact_check.is_active() {
+                                    "exact "
+                                } else {
+                                    ""
+                                },
+                                x_spin.value() as i64,
+                                y_spin.value() as i64
+                            );
+                            page.update(index, |bind| bind.args = text.clone());
+                        })
+                    };
+                    let write = write_vec2.clone();
+                    exact_check.connect_toggled(move |_| write());
+                    let write = write_vec2.clone();
+                    x_spin.connect_value_changed(move |_| write());
+                    y_spin.connect_value_changed(move |_| write_vec2());
+                    return vec2_box.upcast();
+                }
+            }
+            _ => {}
+        }
+
+        let entry = Entry::new();
+        entry.set_placeholder_text(Some(kind.placeholder()));
+        entry.set_hexpand(true);
+        entry.set_text(args);
+        if !kind.is_valid(args) {
+            entry.add_css_class("error");
+        }
+        let page = self.clone();
+        entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            if kind.is_valid(&text) {
+                entry.remove_css_class("error");
+            } else {
+                entry.add_css_class("error");
+            }
+            page.update(index, |bind| bind.args = text.clone());
+        });
+        entry.upcast()
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let directive = self.binds.borrow()[index].clone();
+        let bind = Bind::parse(&directive.keyword, &directive.value).unwrap_or_default();
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+        hbox.set_margin_start(5);
+        hbox.set_margin_end(5);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let mut keywords: Vec<&str> = BIND_KEYWORDS.to_vec();
+        if !keywords.contains(&directive.keyword.as_str()) {
+            keywords.push(&directive.keyword);
+        }
+        let keyword_dropdown =
+            DropDown::new(Some(StringList::new(&keywords)), None::<gtk::Expression>);
+        if let Some(position) = keywords.iter().position(|k| *k == directive.keyword) {
+            keyword_dropdown.set_selected(position as u32);
+        }
+
+        let mods_entry = Entry::new();
+        mods_entry.set_placeholder_text(Some("Modifiers"));
+        mods_entry.set_width_chars(12);
+        mods_entry.set_text(&bind.mods);
+
+        let key_entry = Entry::new();
+        key_entry.set_placeholder_text(Some("Key"));
+        key_entry.set_width_chars(8);
+        key_entry.set_text(&bind.key);
+
+        let record_button = Button::from_icon_name("media-record-symbolic");
+        record_button.set_has_frame(false);
+        record_button.set_tooltip_text(Some("Record key combination"));
+        let record_popover = build_record_popover(&mods_entry, &key_entry);
+        record_popover.set_parent(&record_button);
+
+        let description_entry = Entry::new();
+        description_entry.set_placeholder_text(Some("Description"));
+        description_entry.set_width_chars(12);
+        description_entry.set_text(&bind.description);
+        description_entry.set_visible(bind.flags.contains('d'));
+
+        let mut dispatchers: Vec<&str> = DISPATCHERS.iter().map(|(name, _)| *name).collect();
+        if !dispatchers.contains(&bind.dispatcher.as_str()) {
+            dispatchers.push(&bind.dispatcher);
+        }
+        let dispatcher_dropdown = DropDown::new(
+            Some(StringList::new(&dispatchers)),
+            Some(gtk::PropertyExpression::new(
+                gtk::StringObject::static_type(),
+                None::<gtk::Expression>,
+                "string",
+            )),
+        );
+        dispatcher_dropdown.set_enable_search(true);
+        if let Some(position) = dispatchers.iter().position(|d| *d == bind.dispatcher) {
+            dispatcher_dropdown.set_selected(position as u32);
+        }
+
+        let args_box = Box::new(Orientation::Horizontal, 5);
+        args_box.set_hexpand(true);
+        args_box.append(&self.build_args_widget(index, &bind.dispatcher, &bind.args));
+
+        let files = self.files.borrow();
+        let file_labels: Vec<&str> = files.iter().map(String::as_str).collect();
+        let file_dropdown =
+            DropDown::new(Some(StringList::new(&file_labels)), None::<gtk::Expression>);
+        file_dropdown.set_selected(directive.file as u32);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.binds.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&keyword_dropdown);
+        hbox.append(&mods_entry);
+        hbox.append(&key_entry);
+        hbox.append(&record_button);
+        hbox.append(&description_entry);
+        hbox.append(&dispatcher_dropdown);
+        hbox.append(&args_box);
+        if let Some(submap) = &directive.submap {
+            let submap_label = Label::new(Some(&format!("submap: {}", submap)));
+            submap_label.set_opacity(0.7);
+            hbox.append(&submap_label);
+        }
+        hbox.append(&file_dropdown);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        let page = self.clone();
+        let description_entry_clone = description_entry.clone();
+        keyword_dropdown.connect_selected_notify(move |dd| {
+            if let Some(selected) = dd.selected_item() {
+                if let Some(string_object) = selected.downcast_ref::<gtk::StringObject>() {
+                    let keyword = string_object.string().to_string();
+                    let flags = keyword.trim_start_matches("bind").to_string();
+                    description_entry_clone.set_visible(flags.contains('d'));
+                    page.update(index, |bind| bind.flags = flags.clone());
+                }
+            }
+        });
+
+        let record_popover_clone = record_popover.clone();
+        record_button.connect_clicked(move |_| {
+            record_popover_clone.popup();
+        });
+        record_button.connect_destroy(move |_| {
+            record_popover.unparent();
+        });
+
+        let page = self.clone();
+        mods_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |bind| bind.mods = text.clone());
+        });
+
+        let page = self.clone();
+        key_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |bind| bind.key = text.clone());
+        });
+
+        let page = self.clone();
+        description_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |bind| bind.description = text.clone());
+        });
+
+        let page = self.clone();
+        dispatcher_dropdown.connect_selected_notify(move |dd| {
+            if let Some(selected) = dd.selected_item() {
+                if let Some(string_object) = selected.downcast_ref::<gtk::StringObject>() {
+                    let dispatcher = string_object.string().to_string();
+                    let args = match get_dispatcher_arg(&dispatcher) {
+                        DispatcherArg::Choice(choices) => choices[0].to_string(),
+                        DispatcherArg::Direction => "l".to_string(),
+                        DispatcherArg::Vec2 => "0 0".to_string(),
+                        DispatcherArg::Int => "0".to_string(),
+                        _ => String::new(),
+                    };
+                    page.update(index, |bind| {
+                        bind.dispatcher = dispatcher.clone();
+                        bind.args = args.clone();
+                    });
+                    while let Some(child) = args_box.first_child() {
+                        args_box.remove(&child);
+                    }
+                    args_box.append(&page.build_args_widget(index, &dispatcher, &args));
+                }
+            }
+        });
+
+        let page = self.clone();
+        file_dropdown.connect_selected_notify(move |dd| {
+            if let Some(directive) = page.binds.borrow_mut().get_mut(index) {
+                directive.file = dd.selected() as usize;
+                directive.line = None;
+            }
+            *page.changed.borrow_mut() = true;
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.binds.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.binds.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.binds.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        hbox
+    }
+}
+
+fn build_record_popover(mods_entry: &Entry, key_entry: &Entry) -> Popover {
+    let popover = Popover::new();
+    popover.set_position(gtk::PositionType::Bottom);
+
+    let vbox = Box::new(Orientation::Vertical, 5);
+    vbox.set_margin_top(5);
+    vbox.set_margin_bottom(5);
+    vbox.set_margin_start(5);
+    vbox.set_margin_end(5);
+
+    let hint_label = Label::new(Some("Press a key combination..."));
+    let format_dropdown = DropDown::new(
+        Some(StringList::new(&["Keysym", "Keycode (code:)"])),
+        None::<gtk::Expression>,
+    );
+
+    vbox.append(&hint_label);
+    vbox.append(&format_dropdown);
+    popover.set_child(Some(&vbox));
+
+    let popover_clone = popover.clone();
+    let mods_entry = mods_entry.clone();
+    let key_entry = key_entry.clone();
+    let key_controller = EventControllerKey::new();
+    key_controller.set_propagation_phase(gtk::PropagationPhase::Capture);
+    key_controller.connect_key_pressed(move |controller, key, keycode, state| {
+        let key = popover_clone
+            .display()
+            .translate_key(
+                keycode,
+                gdk::ModifierType::empty(),
+                controller.group() as i32,
+            )
+            .map_or(key, |(key, ..)| key);
+        match format_combo(key, keycode, state, format_dropdown.selected() == 1) {
+            Some((mods, key)) => {
+                mods_entry.set_text(&mods);
+                key_entry.set_text(&key);
+                popover_clone.popdown();
+                glib::Propagation::Stop
+            }
+            None => glib::Propagation::Proceed,
+        }
+    });
+    popover.add_controller(key_controller);
+
+    popover
+}
+
+fn format_combo(
+    key: gdk::Key,
+    keycode: u32,
+    state: gdk::ModifierType,
+    use_keycode: bool,
+) -> Option<(String, String)> {
+    let name = key.to_lower().name()?.to_string();
+    let modifier_keys = [
+        "Shift_L",
+        "Shift_R",
+        "Control_L",
+        "Control_R",
+        "Alt_L",
+        "Alt_R",
+        "Super_L",
+        "Super_R",
+        "Meta_L",
+        "Meta_R",
+        "Hyper_L",
+        "Hyper_R",
+        "ISO_Level3_Shift",
+        "Caps_Lock",
+        "Num_Lock",
+    ];
+    if modifier_keys.contains(&name.as_str()) {
+        return None;
+    }
+
+    let mut mods = Vec::new();
+    if state.contains(gdk::ModifierType::SUPER_MASK) {
+        mods.push("SUPER");
+    }
+    if state.contains(gdk::ModifierType::CONTROL_MASK) {
+        mods.push("CTRL");
+    }
+    if state.contains(gdk::ModifierType::ALT_MASK) {
+        mods.push("ALT");
+    }
+    if state.contains(gdk::ModifierType::SHIFT_MASK) {
+        mods.push("SHIFT");
+    }
+
+    let key = if use_keycode {
+        format!("code:{}", keycode)
+    } else {
+        name
+    };
+
+    Some((mods.join(" "), key))
+}
@@ -0,0 +1,301 @@
+use gtk::{
+    glib, prelude::*, Box, Button, CheckButton, DropDown, Entry, Label, ListBox, Orientation,
+    ScrolledWindow, SelectionMode, SpinButton, StringList, Switch, Widget,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::rules::{LayerRule, LAYER_ANIMATION_STYLES, LAYER_RULE_EFFECTS};
+use crate::widgets::WidgetBuilder;
+
+#[derive(Clone)]
+pub struct LayerRulesPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    rules: Rc<RefCell<Vec<Directive>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl LayerRulesPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Layer Rules",
+            "Blur, animation and ordering tweaks for bars, launchers and notifications.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Layer Rule");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            rules: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            page_clone
+                .rules
+                .borrow_mut()
+                .push(Directive::new("layerrule", "blur, ", 0, None));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.rules.borrow_mut() = directives::collect(config, &|k| k == "layerrule");
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &|k| k == "layerrule", &self.rules.borrow());
+        }
+    }
+
+    pub fn invalid_rules(&self) -> Vec<String> {
+        if !self.has_changes() {
+            return Vec::new();
+        }
+        self.rules
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| LayerRule::parse(&d.value).namespace.trim().is_empty())
+            .map(|(index, d)| format!("row {}: layerrule = {}", index + 1, d.value))
+            .collect()
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+
+        let count = self.rules.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut LayerRule)) {
+        if let Some(directive) = self.rules.borrow_mut().get_mut(index) {
+            let mut rule = LayerRule::parse(&directive.value);
+            edit(&mut rule);
+            directive.value = rule.value();
+        }
+        *self.changed.borrow_mut() = true;
+    }
+
+    fn build_args_widget(&self, index: usize, effect: &str, args: &str) -> Widget {
+        match effect {
+            "ignorealpha" | "order" => {
+                let spin_button = if effect == "order" {
+                    SpinButton::with_range(-100.0, 100.0, 1.0)
+                } else {
+                    let spin_button = SpinButton::with_range(0.0, 1.0, 0.01);
+                    spin_button.set_digits(2);
+                    spin_button
+                };
+                spin_button.set_value(args.parse().unwrap_or(0.0));
+
+                let page = self.clone();
+                spin_button.connect_value_changed(move |sb| {
+                    let value = if sb.digits() == 0 {
+                        sb.value_as_int().to_string()
+                    } else {
+                        format!("{:.2}", sb.value())
+                    };
+                    page.update(index, |r| r.args = value.clone());
+                });
+                spin_button.upcast()
+            }
+            "animation" => {
+                let mut styles: Vec<&str> = LAYER_ANIMATION_STYLES.to_vec();
+                if !styles.contains(&args) {
+                    styles.push(args);
+                }
+                let dropdown =
+                    DropDown::new(Some(StringList::new(&styles)), None::<gtk::Expression>);
+                if let Some(position) = styles.iter().position(|style| *style == args) {
+                    dropdown.set_selected(position as u32);
+                }
+
+                let page = self.clone();
+                dropdown.connect_selected_notify(move |dd| {
+                    let style = dd
+                        .selected_item()
+                        .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+                        .map(|item| item.string().to_string())
+                        .unwrap_or_default();
+                    page.update(index, |r| r.args = style.clone());
+                });
+                dropdown.upcast()
+            }
+            "xray" => {
+                let switch = Switch::new();
+                switch.set_valign(gtk::Align::Center);
+                switch.set_active(args != "0");
+
+                let page = self.clone();
+                switch.connect_state_set(move |_, state| {
+                    let value = if state { "1" } else { "0" };
+                    page.update(index, |r| r.args = value.to_string());
+                    glib::Propagation::Proceed
+                });
+                switch.upcast()
+            }
+            "abovelock" => {
+                let check = CheckButton::with_label("Interactable");
+                check.set_active(matches!(args, "1" | "true"));
+
+                let page = self.clone();
+                check.connect_toggled(move |check| {
+                    let value = if check.is_active() { "1" } else { "" };
+                    page.update(index, |r| r.args = value.to_string());
+                });
+                check.upcast()
+            }
+            _ => {
+                let label = Label::new(Some("no value"));
+                label.set_opacity(0.7);
+                label.upcast()
+            }
+        }
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let rule = LayerRule::parse(&self.rules.borrow()[index].value);
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+        hbox.set_margin_start(5);
+        hbox.set_margin_end(5);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let mut effects: Vec<&str> = LAYER_RULE_EFFECTS.to_vec();
+        if !effects.contains(&rule.effect.as_str()) {
+            effects.push(&rule.effect);
+        }
+        let effect_dropdown =
+            DropDown::new(Some(StringList::new(&effects)), None::<gtk::Expression>);
+        if let Some(position) = effects.iter().position(|e| *e == rule.effect) {
+            effect_dropdown.set_selected(position as u32);
+        }
+
+        let args_box = Box::new(Orientation::Horizontal, 5);
+        args_box.set_width_request(160);
+        args_box.append(&self.build_args_widget(index, &rule.effect, &rule.args));
+
+        let namespace_entry = Entry::new();
+        namespace_entry.set_placeholder_text(Some("Namespace (e.g. waybar, rofi)"));
+        namespace_entry.set_text(&rule.namespace);
+        namespace_entry.set_hexpand(true);
+        namespace_entry
+            .set_tooltip_text(Some("Required: the layer namespace this rule applies to"));
+        if rule.namespace.trim().is_empty() {
+            namespace_entry.add_css_class("error");
+        }
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.rules.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&effect_dropdown);
+        hbox.append(&args_box);
+        hbox.append(&namespace_entry);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        let page = self.clone();
+        effect_dropdown.connect_selected_notify(move |dd| {
+            let effect = dd
+                .selected_item()
+                .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+                .map(|item| item.string().to_string())
+                .unwrap_or_default();
+            let args = LayerRule::default_args(&effect);
+            page.update(index, |r| {
+                r.effect = effect.clone();
+                r.args = args.to_string();
+            });
+            while let Some(child) = args_box.first_child() {
+                args_box.remove(&child);
+            }
+            args_box.append(&page.build_args_widget(index, &effect, args));
+        });
+
+        let page = self.clone();
+        namespace_entry.connect_changed(move |entry| {
+            let namespace = entry.text().to_string();
+            if namespace.trim().is_empty() {
+                entry.add_css_class("error");
+            } else {
+                entry.remove_css_class("error");
+            }
+            page.update(index, |r| r.namespace = namespace.clone());
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        hbox
+    }
+}
@@ -2,12 +2,30 @@ use gtk::{prelude::*, Application, Button, FileChooserAction, FileChooserDialog}
 use hyprparser::parse_config;
 use std::{cell::RefCell, env, fs, path::Path, path::PathBuf, rc::Rc};
 
+mod animations;
+mod atomic;
+mod autostart;
+mod backups;
+mod binds;
+mod clients;
+mod defaults;
+mod diff;
+mod directives;
+mod environment;
 mod gui;
+mod history;
+mod keybinds;
+mod layerrules;
 mod modules;
+mod monitors;
+mod rules;
+mod variables;
+mod watcher;
 mod widgets;
+mod windowrules;
+mod workspaces;
 
 const CONFIG_PATH: &str = ".config/hypr/hyprland.conf";
-const BACKUP_SUFFIX: &str = "-bak";
 static CONFIG_PATH_OVERRIDE: std::sync::Mutex<Option<PathBuf>> = std::sync::Mutex::new(None);
 
 fn main() {
@@ -45,6 +63,20 @@ fn build_ui(app: &Application) {
         };
         let parsed_config = parse_config(&config_str);
         gui.borrow_mut().load_config(&parsed_config);
+        watch_config_files(gui.clone());
+
+        let gui_clone = gui.clone();
+        gui.borrow()
+            .watcher
+            .reload_button
+            .connect_clicked(move |_| {
+                reload_config(gui_clone.clone());
+            });
+
+        let gui_clone = gui.clone();
+        gui.borrow().watcher.merge_button.connect_clicked(move |_| {
+            merge_config(gui_clone.clone());
+        });
 
         let gui_clone = gui.clone();
         gui.borrow().save_button.connect_clicked(move |button| {
@@ -61,7 +93,7 @@ fn build_ui(app: &Application) {
             filter_options(gui_clone.clone(), entry.text());
         });
 
-        let undo_button = Button::with_label("Undo Changes");
+        let backups_button = Button::with_label("Backup History");
         let copy_button = Button::with_label("Copyright");
         let choose_config_button = Button::with_label("Choose Hyprland Config");
 
@@ -112,6 +144,7 @@ fn build_ui(app: &Application) {
                                     .borrow_mut()
                                     .clear();
                             }
+                            watch_config_files(gui_clone_inner.clone());
                         }
                     }
                 }
@@ -122,14 +155,14 @@ fn build_ui(app: &Application) {
         });
 
         let gui_clone = gui.clone();
-        undo_button.connect_clicked(move |button| {
+        backups_button.connect_clicked(move |button| {
             if let Some(popover) = button.ancestor(gtk::Popover::static_type()) {
                 if let Some(popover) = popover.downcast_ref::<gtk::Popover>() {
                     popover.popdown();
                 }
             }
 
-            undo_changes(gui_clone.clone());
+            browse_backups(gui_clone.clone());
         });
 
         let gui_clone = gui.clone();
@@ -160,7 +193,7 @@ along with this program; if not, see
         if let Some(gear_menu_box) = gui.borrow().gear_menu.borrow().child() {
             if let Some(box_widget) = gear_menu_box.downcast_ref::<gtk::Box>() {
                 box_widget.append(&choose_config_button);
-                box_widget.append(&undo_button);
+                box_widget.append(&backups_button);
                 box_widget.append(&copy_button);
             }
         }
@@ -220,11 +253,6 @@ fn filter_options(gui: Rc<RefCell<gui::ConfigGUI>>, search_text: impl AsRef<str>
 fn save_config_file(gui: Rc<RefCell<gui::ConfigGUI>>) {
     let mut gui_ref = gui.borrow_mut();
     let path = get_config_path();
-    let backup_path = path.with_file_name(format!(
-        "{}{}",
-        path.file_name().unwrap().to_str().unwrap(),
-        BACKUP_SUFFIX
-    ));
 
     let config_str = match fs::read_to_string(&path) {
         Ok(s) => s,
@@ -239,155 +267,335 @@ fn save_config_file(gui: Rc<RefCell<gui::ConfigGUI>>) {
     };
 
     let mut parsed_config = parse_config(&config_str);
-    let changes = gui_ref.get_changes();
-
-    if !changes.borrow().is_empty() {
-        if !backup_path.exists() {
-            if let Err(e) = fs::copy(&path, &backup_path) {
-                gui_ref.custom_error_popup(
-                    "Backup failed",
-                    &format!("Failed to create backup: {}", e),
-                    true,
-                );
-                return;
-            }
 
-            for sourced_path in &parsed_config.sourced_paths {
-                let sourced_backup = Path::new(sourced_path).with_file_name(format!(
-                    "{}{}",
-                    Path::new(sourced_path)
-                        .file_name()
-                        .unwrap()
-                        .to_str()
-                        .unwrap(),
-                    BACKUP_SUFFIX
-                ));
-                if let Err(e) = fs::copy(sourced_path, &sourced_backup) {
-                    gui_ref.custom_error_popup(
-                        "Backup failed",
-                        &format!("Failed to create backup for sourced file: {}", e),
-                        true,
-                    );
-                    return;
-                }
-            }
-        }
+    if !check_invalid_entries(&mut gui_ref) {
+        return;
+    }
 
-        gui_ref.apply_changes(&mut parsed_config);
+    if !gui_ref.has_changes() {
+        gui_ref.custom_info_popup("Saving failed", "No changes to save.", true);
+        return;
+    }
 
-        let updated_config_str = parsed_config.to_string();
+    gui_ref.apply_changes(&mut parsed_config);
 
-        match fs::write(&path, updated_config_str) {
-            Ok(_) => println!("Configuration saved successfully"),
-            Err(e) => {
-                gui_ref.custom_error_popup(
-                    "Saving failed",
-                    &format!("Failed to save the configuration: {}", e),
-                    true,
-                );
-            }
-        }
-    } else {
+    let labels = directives::file_labels(&parsed_config);
+    let mut pending = Vec::new();
+    let updated_config: Vec<String> = parsed_config.content.clone();
+    pending.extend(pending_file(
+        path.clone(),
+        labels[0].clone(),
+        &config_str,
+        updated_config,
+    ));
+    for (index, (sourced_path, lines)) in parsed_config
+        .sourced_paths
+        .iter()
+        .zip(&parsed_config.sourced_content)
+        .enumerate()
+    {
+        let current = fs::read_to_string(sourced_path).unwrap_or_default();
+        pending.extend(pending_file(
+            PathBuf::from(sourced_path),
+            labels[index + 1].clone(),
+            &current,
+            lines.clone(),
+        ));
+    }
+
+    if pending.is_empty() {
         gui_ref.custom_info_popup("Saving failed", "No changes to save.", true);
+        return;
     }
+
+    let sourced_paths = parsed_config.sourced_paths.clone();
+    let gui_clone = gui.clone();
+    diff::show_diff_dialog(&gui_ref.window, pending, move |writes| {
+        write_config_files(gui_clone.clone(), &sourced_paths, writes);
+    });
 }
 
-fn undo_changes(gui: Rc<RefCell<gui::ConfigGUI>>) {
+fn check_invalid_entries(gui_ref: &mut gui::ConfigGUI) -> bool {
+    let invalid_keybinds = gui_ref.invalid_keybinds();
+    if !invalid_keybinds.is_empty() {
+        gui_ref.custom_error_popup(
+            "Invalid keybindings",
+            &format!(
+                "Fix the arguments of the following keybindings before saving:\n{}",
+                invalid_keybinds.join("\n")
+            ),
+            true,
+        );
+        return false;
+    }
+
+    let invalid_variables = gui_ref.invalid_variables();
+    if !invalid_variables.is_empty() {
+        gui_ref.custom_error_popup(
+            "Invalid variables",
+            &format!(
+                "Variable names may only contain letters, digits and underscores. Fix the following variables before saving:\n{}",
+                invalid_variables.join("\n")
+            ),
+            true,
+        );
+        return false;
+    }
+
+    let invalid_layer_rules = gui_ref.invalid_layer_rules();
+    if !invalid_layer_rules.is_empty() {
+        gui_ref.custom_error_popup(
+            "Invalid layer rules",
+            &format!(
+                "Enter a namespace for the following layer rules before saving:\n{}",
+                invalid_layer_rules.join("\n")
+            ),
+            true,
+        );
+        return false;
+    }
+
+    true
+}
+
+fn pending_file(
+    path: PathBuf,
+    label: String,
+    current: &str,
+    updated: Vec<String>,
+) -> Option<diff::PendingFile> {
+    let old: Vec<String> = current.lines().map(String::from).collect();
+    let hunks = diff::diff_hunks(&old, &updated);
+    if hunks.is_empty() {
+        return None;
+    }
+    Some(diff::PendingFile {
+        path,
+        label,
+        old,
+        hunks,
+        conflicts: Vec::new(),
+    })
+}
+
+fn write_config_files(
+    gui: Rc<RefCell<gui::ConfigGUI>>,
+    sourced_paths: &[String],
+    writes: Vec<(PathBuf, Vec<String>)>,
+) -> bool {
     let mut gui_ref = gui.borrow_mut();
-    let path = get_config_path();
-    let backup_path = path.with_file_name(format!(
-        "{}{}",
-        path.file_name().unwrap().to_str().unwrap(),
-        BACKUP_SUFFIX
-    ));
+    if writes.is_empty() {
+        gui_ref.custom_info_popup("Saving skipped", "No changes were selected.", true);
+        return false;
+    }
 
-    if backup_path.exists() {
-        let config_str = match fs::read_to_string(&path) {
-            Ok(s) => s,
-            Err(e) => {
-                gui_ref.custom_error_popup(
-                    "Reading failed",
-                    &format!("Failed to read configuration: {}", e),
-                    true,
-                );
-                return;
-            }
-        };
+    let mut paths = vec![get_config_path()];
+    paths.extend(sourced_paths.iter().map(PathBuf::from));
+    if let Err(e) = backups::create_snapshot(&paths, None) {
+        gui_ref.custom_error_popup(
+            "Backup failed",
+            &format!("Failed to create backup: {}", e),
+            true,
+        );
+        return false;
+    }
 
-        let parsed_config = parse_config(&config_str);
+    let writes: Vec<(PathBuf, String)> = writes
+        .into_iter()
+        .map(|(file_path, lines)| (file_path, lines.join("\n")))
+        .collect();
+    if let Err(e) = atomic::write_files(&writes) {
+        gui_ref.custom_error_popup(
+            "Saving failed",
+            &format!("Failed to save, no files were changed: {}", e),
+            true,
+        );
+        return false;
+    }
+    println!("Configuration saved successfully");
+    drop(gui_ref);
+    watch_config_files(gui);
+    true
+}
 
-        for sourced_path in &parsed_config.sourced_paths {
-            let sourced_backup = Path::new(sourced_path).with_file_name(format!(
-                "{}{}",
-                Path::new(sourced_path)
-                    .file_name()
-                    .unwrap()
-                    .to_str()
-                    .unwrap(),
-                BACKUP_SUFFIX
-            ));
-            if sourced_backup.exists() {
-                if let Err(e) = fs::copy(&sourced_backup, sourced_path) {
-                    gui_ref.custom_error_popup(
-                        "Undo Failed",
-                        &format!("Failed to restore sourced file from backup: {}", e),
-                        true,
-                    );
-                    return;
-                }
-                if let Err(e) = fs::remove_file(&sourced_backup) {
-                    gui_ref.custom_error_popup(
-                        "Backup Deletion Failed",
-                        &format!("Failed to delete sourced backup file: {}", e),
-                        true,
-                    );
-                }
-            }
-        }
+fn browse_backups(gui: Rc<RefCell<gui::ConfigGUI>>) {
+    let gui_clone = gui.clone();
+    backups::show_backup_browser(&gui.borrow().window, move |snapshot| {
+        restore_backup(gui_clone.clone(), snapshot);
+    });
+}
 
-        match fs::copy(&backup_path, &path) {
-            Ok(_) => {
-                if let Ok(config_str) = fs::read_to_string(&path) {
-                    let parsed_config = parse_config(&config_str);
-                    gui_ref.load_config(&parsed_config);
-                    gui_ref.get_changes().borrow_mut().clear();
-
-                    if let Err(e) = fs::remove_file(&backup_path) {
-                        gui_ref.custom_error_popup(
-                            "Backup Deletion Failed",
-                            &format!("Failed to delete the backup file: {}", e),
-                            true,
-                        );
-                    } else {
-                        gui_ref.custom_info_popup(
-                            "Undo Successful",
-                            "Configuration restored from backup and backup files deleted.",
-                            true,
-                        );
-                    }
-                } else {
-                    gui_ref.custom_error_popup(
-                        "Reload Failed",
-                        "Failed to reload the configuration after undo.",
-                        true,
-                    );
-                }
-            }
-            Err(e) => {
-                gui_ref.custom_error_popup(
-                    "Undo Failed",
-                    &format!("Failed to restore from backup: {}", e),
-                    true,
-                );
-            }
+fn restore_backup(gui: Rc<RefCell<gui::ConfigGUI>>, snapshot: &backups::Snapshot) {
+    let mut gui_ref = gui.borrow_mut();
+    let path = get_config_path();
+
+    let mut paths = config_files(&path);
+    for (snapshot_path, _) in &snapshot.files {
+        if !paths.contains(snapshot_path) {
+            paths.push(snapshot_path.clone());
         }
-    } else {
+    }
+    if let Err(e) = backups::create_snapshot(&paths, Some(&snapshot.dir)) {
+        gui_ref.custom_error_popup(
+            "Backup failed",
+            &format!("Failed to back up the current configuration: {}", e),
+            true,
+        );
+        return;
+    }
+
+    if let Err(e) = backups::restore(snapshot) {
         gui_ref.custom_error_popup(
-            "Undo Failed",
-            "No backup file found. Save changes at least once to create a backup.",
+            "Restore Failed",
+            &format!("Failed to restore from backup: {}", e),
+            true,
+        );
+        return;
+    }
+
+    match fs::read_to_string(&path) {
+        Ok(config_str) => {
+            let parsed_config = parse_config(&config_str);
+            gui_ref.load_config(&parsed_config);
+            gui_ref.get_changes().borrow_mut().clear();
+            gui_ref.custom_info_popup(
+                "Restore Successful",
+                &format!(
+                    "Configuration restored from the backup taken {}.",
+                    snapshot.title()
+                ),
+                true,
+            );
+        }
+        Err(_) => {
+            gui_ref.custom_error_popup(
+                "Reload Failed",
+                "Failed to reload the configuration after restoring.",
+                true,
+            );
+            return;
+        }
+    }
+    drop(gui_ref);
+    watch_config_files(gui);
+}
+
+fn config_files(path: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![path.to_path_buf()];
+    if let Ok(config_str) = fs::read_to_string(path) {
+        let parsed_config = parse_config(&config_str);
+        paths.extend(parsed_config.sourced_paths.iter().map(PathBuf::from));
+    }
+    paths
+}
+
+fn watch_config_files(gui: Rc<RefCell<gui::ConfigGUI>>) {
+    let paths = config_files(&get_config_path());
+    let gui_clone = gui.clone();
+    gui.borrow().watcher.watch(paths, move || {
+        config_changed_on_disk(gui_clone.clone());
+    });
+}
+
+fn config_changed_on_disk(gui: Rc<RefCell<gui::ConfigGUI>>) {
+    let Ok(gui_ref) = gui.try_borrow() else {
+        return;
+    };
+    let changed = gui_ref.watcher.changed_files();
+    if changed.is_empty() {
+        gui_ref.watcher.hide();
+        return;
+    }
+
+    let names: Vec<String> = changed
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().to_string())
+        .collect();
+    if gui_ref.has_changes() {
+        gui_ref.watcher.show(
+            &format!(
+                "{} changed on disk. Merge those edits with your unsaved changes, or reload and discard them.",
+                names.join(", ")
+            ),
             true,
         );
+    } else {
+        gui_ref
+            .watcher
+            .show(&format!("{} changed on disk.", names.join(", ")), false);
+    }
+}
+
+fn reload_config(gui: Rc<RefCell<gui::ConfigGUI>>) {
+    let mut gui_ref = gui.borrow_mut();
+    match fs::read_to_string(get_config_path()) {
+        Ok(config_str) => {
+            let parsed_config = parse_config(&config_str);
+            gui_ref.load_config(&parsed_config);
+            gui_ref.get_changes().borrow_mut().clear();
+        }
+        Err(e) => {
+            gui_ref.custom_error_popup(
+                "Reading failed",
+                &format!("Failed to read the configuration file: {}", e),
+                true,
+            );
+            return;
+        }
+    }
+    drop(gui_ref);
+    watch_config_files(gui);
+}
+
+fn merge_config(gui: Rc<RefCell<gui::ConfigGUI>>) {
+    let mut gui_ref = gui.borrow_mut();
+    if !check_invalid_entries(&mut gui_ref) {
+        return;
+    }
+    let base = gui_ref.watcher.files();
+    let Some(((_, main_base), sourced_base)) = base.split_first() else {
+        return;
+    };
+
+    let sourced: Vec<(String, String)> = sourced_base
+        .iter()
+        .map(|(path, content)| (path.to_string_lossy().to_string(), content.clone()))
+        .collect();
+    let mut merged_config = directives::parse_snapshot(main_base, &sourced);
+    gui_ref.apply_changes(&mut merged_config);
+
+    let labels = directives::file_labels(&merged_config);
+    let mut pending = Vec::new();
+    for (index, (path, base_content)) in base.iter().enumerate() {
+        let current = fs::read_to_string(path).unwrap_or_default();
+        let base_lines: Vec<String> = base_content.lines().map(String::from).collect();
+        let current_lines: Vec<String> = current.lines().map(String::from).collect();
+        let (merged, conflicts) = diff::merge3(
+            &base_lines,
+            directives::file_lines(&merged_config, index),
+            &current_lines,
+        );
+        if let Some(mut file) = pending_file(path.clone(), labels[index].clone(), &current, merged)
+        {
+            file.conflicts = conflicts;
+            pending.push(file);
+        }
     }
+
+    if pending.is_empty() {
+        drop(gui_ref);
+        reload_config(gui);
+        return;
+    }
+
+    let sourced_paths: Vec<String> = sourced.into_iter().map(|(path, _)| path).collect();
+    let gui_clone = gui.clone();
+    diff::show_diff_dialog(&gui_ref.window, pending, move |writes| {
+        if write_config_files(gui_clone.clone(), &sourced_paths, writes) {
+            reload_config(gui_clone.clone());
+        }
+    });
 }
 
 fn get_config_path() -> PathBuf {
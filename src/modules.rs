@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::gui::add_dropdown_option;
+use crate::history::History;
 use crate::widgets::WidgetBuilder;
 
 pub struct ConfigWidget {
@@ -1916,9 +1917,10 @@ impl ConfigWidget {
         config: &HyprlandConfig,
         category: &str,
         changed_options: Rc<RefCell<HashMap<(String, String), String>>>,
+        history: History,
     ) {
         let mut builder = WidgetBuilder::new();
         builder.options = self.options.clone();
-        builder.load_config(config, category, changed_options);
+        builder.load_config(config, category, changed_options, history);
     }
 }
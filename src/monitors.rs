@@ -0,0 +1,730 @@
+use gtk::{
+    cairo, prelude::*, Box, Button, CheckButton, DrawingArea, DropDown, Entry, GestureDrag, Label,
+    ListBox, Orientation, ScrolledWindow, SelectionMode, StringList,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::directives::{self, Block, Directive};
+use crate::widgets::WidgetBuilder;
+
+const TRANSFORMS: [&str; 9] = ["", "0", "1", "2", "3", "4", "5", "6", "7"];
+const BITDEPTHS: [&str; 3] = ["", "8", "10"];
+const VRR_MODES: [&str; 5] = ["", "0", "1", "2", "3"];
+const COLOR_MODES: [&str; 7] = ["", "auto", "srgb", "wide", "edid", "hdr", "hdredid"];
+const MIN_MONITOR_SIZE: f64 = 100.0;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Monitor {
+    pub name: String,
+    pub resolution: String,
+    pub position: String,
+    pub scale: String,
+    pub transform: String,
+    pub mirror: String,
+    pub bitdepth: String,
+    pub vrr: String,
+    pub cm: String,
+    pub disabled: bool,
+    pub extra: Vec<(String, String)>,
+}
+
+impl Monitor {
+    pub fn parse(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(',').map(str::trim).collect();
+        let mut monitor = Monitor {
+            name: parts[0].to_string(),
+            ..Monitor::default()
+        };
+
+        if matches!(parts.get(1), Some(&"disable") | Some(&"disabled")) {
+            monitor.disabled = true;
+            return monitor;
+        }
+
+        monitor.resolution = parts.get(1).unwrap_or(&"preferred").to_string();
+        monitor.position = parts.get(2).unwrap_or(&"auto").to_string();
+        monitor.scale = parts.get(3).unwrap_or(&"1").to_string();
+
+        let mut rest = parts.iter().skip(4);
+        while let Some(key) = rest.next() {
+            let value = rest.next().unwrap_or(&"").to_string();
+            monitor.set(key, value);
+        }
+        monitor
+    }
+
+    pub fn parse_block(entries: &[(String, String)]) -> Self {
+        let mut monitor = Monitor::default();
+        for (key, value) in entries {
+            match key.as_str() {
+                "output" => monitor.name = value.clone(),
+                "mode" => monitor.resolution = value.clone(),
+                "position" => monitor.position = value.clone(),
+                "scale" => monitor.scale = value.clone(),
+                "disabled" => monitor.disabled = matches!(value.as_str(), "true" | "1" | "yes"),
+                _ => monitor.set(key, value.clone()),
+            }
+        }
+        monitor
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        match key {
+            "transform" => self.transform = value,
+            "mirror" => self.mirror = value,
+            "bitdepth" => self.bitdepth = value,
+            "vrr" => self.vrr = value,
+            "cm" => self.cm = value,
+            _ => self.extra.push((key.to_string(), value)),
+        }
+    }
+
+    fn optional_fields(&self) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        for (key, value) in [
+            ("transform", &self.transform),
+            ("mirror", &self.mirror),
+            ("bitdepth", &self.bitdepth),
+            ("vrr", &self.vrr),
+            ("cm", &self.cm),
+        ] {
+            if !value.is_empty() {
+                fields.push((key.to_string(), value.clone()));
+            }
+        }
+        fields.extend(self.extra.iter().cloned());
+        fields
+    }
+
+    pub fn value(&self) -> String {
+        if self.disabled {
+            return format!("{}, disable", self.name);
+        }
+        let mut parts = vec![
+            self.name.clone(),
+            self.resolution.clone(),
+            self.position.clone(),
+            self.scale.clone(),
+        ];
+        for (key, value) in self.optional_fields() {
+            parts.push(key);
+            if !value.is_empty() {
+                parts.push(value);
+            }
+        }
+        parts.join(", ")
+    }
+
+    pub fn block_entries(&self) -> Vec<(String, String)> {
+        let mut entries = vec![("output".to_string(), self.name.clone())];
+        if self.disabled {
+            entries.push(("disabled".to_string(), "true".to_string()));
+            return entries;
+        }
+        entries.push(("mode".to_string(), self.resolution.clone()));
+        entries.push(("position".to_string(), self.position.clone()));
+        entries.push(("scale".to_string(), self.scale.clone()));
+        entries.extend(self.optional_fields());
+        entries
+    }
+
+    pub fn is_layout(&self) -> bool {
+        !self.disabled && self.resolution != "addreserved" && self.mirror.is_empty()
+    }
+
+    pub fn logical_size(&self) -> (f64, f64) {
+        let mode = self.resolution.split('@').next().unwrap_or_default();
+        let (width, height) = mode
+            .split_once('x')
+            .and_then(|(w, h)| Some((w.parse::<f64>().ok()?, h.parse::<f64>().ok()?)))
+            .filter(|(w, h)| w.is_finite() && h.is_finite())
+            .unwrap_or((1920.0, 1080.0));
+        let scale = self
+            .scale
+            .parse::<f64>()
+            .ok()
+            .filter(|s| *s > 0.0)
+            .unwrap_or(1.0);
+        let (width, height) = (
+            (width / scale).max(MIN_MONITOR_SIZE),
+            (height / scale).max(MIN_MONITOR_SIZE),
+        );
+        if matches!(self.transform.as_str(), "1" | "3" | "5" | "7") {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+
+    pub fn position_xy(&self) -> Option<(f64, f64)> {
+        let (x, y) = self.position.split_once('x')?;
+        let (x, y): (f64, f64) = (x.trim().parse().ok()?, y.trim().parse().ok()?);
+        (x.is_finite() && y.is_finite()).then_some((x, y))
+    }
+}
+
+#[derive(Clone)]
+enum MonitorSource {
+    Line(Directive),
+    Block(Block),
+}
+
+#[derive(Clone)]
+struct MonitorEntry {
+    monitor: Monitor,
+    source: MonitorSource,
+}
+
+struct Layout {
+    zoom: f64,
+    offset_x: f64,
+    offset_y: f64,
+}
+
+struct DragState {
+    index: usize,
+    start: (f64, f64),
+    layout: Layout,
+}
+
+#[derive(Clone)]
+pub struct MonitorsPage {
+    pub scrolled_window: ScrolledWindow,
+    canvas: DrawingArea,
+    list: ListBox,
+    monitors: Rc<RefCell<Vec<MonitorEntry>>>,
+    position_entries: Rc<RefCell<Vec<Entry>>>,
+    drag: Rc<RefCell<Option<DragState>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl MonitorsPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Monitors",
+            "Drag monitors to arrange them, or edit their rules below.",
+            first_section.clone(),
+        );
+
+        let canvas = DrawingArea::new();
+        canvas.set_content_height(300);
+        canvas.set_hexpand(true);
+        container.append(&canvas);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Monitor Rules",
+            "Resolution, position, scale and output options.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Monitor");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        let page = Self {
+            scrolled_window,
+            canvas,
+            list,
+            monitors: Rc::new(RefCell::new(Vec::new())),
+            position_entries: Rc::new(RefCell::new(Vec::new())),
+            drag: Rc::new(RefCell::new(None)),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        page.canvas.set_draw_func(move |_, cr, width, height| {
+            page_clone.draw(cr, width as f64, height as f64);
+        });
+
+        let drag = GestureDrag::new();
+        let page_clone = page.clone();
+        drag.connect_drag_begin(move |_, x, y| {
+            page_clone.drag_begin(x, y);
+        });
+        let page_clone = page.clone();
+        drag.connect_drag_update(move |_, offset_x, offset_y| {
+            page_clone.drag_update(offset_x, offset_y);
+        });
+        let page_clone = page.clone();
+        drag.connect_drag_end(move |_, _, _| {
+            *page_clone.drag.borrow_mut() = None;
+            page_clone.canvas.queue_draw();
+        });
+        page.canvas.add_controller(drag);
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let monitor = Monitor {
+                resolution: "preferred".to_string(),
+                position: "auto".to_string(),
+                scale: "1".to_string(),
+                ..Monitor::default()
+            };
+            page_clone.monitors.borrow_mut().push(MonitorEntry {
+                source: MonitorSource::Line(Directive::new("monitor", "", 0, None)),
+                monitor,
+            });
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        let mut monitors: Vec<MonitorEntry> = directives::collect(config, &|k| k == "monitor")
+            .into_iter()
+            .map(|directive| MonitorEntry {
+                monitor: Monitor::parse(&directive.value),
+                source: MonitorSource::Line(directive),
+            })
+            .collect();
+        monitors.extend(
+            directives::collect_blocks(config, "monitorv2")
+                .into_iter()
+                .map(|block| MonitorEntry {
+                    monitor: Monitor::parse_block(&block.entries),
+                    source: MonitorSource::Block(block),
+                }),
+        );
+        *self.monitors.borrow_mut() = monitors;
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if !self.has_changes() {
+            return;
+        }
+
+        let mut lines = Vec::new();
+        let mut blocks = Vec::new();
+        for entry in self.monitors.borrow().iter() {
+            match &entry.source {
+                MonitorSource::Line(directive) => {
+                    let mut directive = directive.clone();
+                    if Monitor::parse(&directive.value) != entry.monitor {
+                        directive.value = entry.monitor.value();
+                    }
+                    lines.push(directive);
+                }
+                MonitorSource::Block(block) => {
+                    let mut block = block.clone();
+                    if Monitor::parse_block(&block.entries) != entry.monitor {
+                        block.entries = entry.monitor.block_entries();
+                    }
+                    blocks.push(block);
+                }
+            }
+        }
+
+        directives::apply(config, &|k| k == "monitor", &lines);
+        directives::apply_blocks(config, "monitorv2", &blocks);
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+        self.position_entries.borrow_mut().clear();
+
+        let count = self.monitors.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+
+        self.canvas.queue_draw();
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut Monitor)) {
+        if let Some(entry) = self.monitors.borrow_mut().get_mut(index) {
+            edit(&mut entry.monitor);
+        }
+        *self.changed.borrow_mut() = true;
+        self.canvas.queue_draw();
+    }
+
+    fn layout_rects(&self) -> Vec<(usize, f64, f64, f64, f64)> {
+        let mut rects = Vec::new();
+        let mut auto_x = 0.0;
+        for (index, entry) in self.monitors.borrow().iter().enumerate() {
+            if !entry.monitor.is_layout() {
+                continue;
+            }
+            let (width, height) = entry.monitor.logical_size();
+            let (x, y) = entry.monitor.position_xy().unwrap_or((auto_x, 0.0));
+            auto_x = auto_x.max(x + width);
+            rects.push((index, x, y, width, height));
+        }
+        rects
+    }
+
+    fn compute_layout(&self, width: f64, height: f64) -> Layout {
+        let rects = self.layout_rects();
+        if rects.is_empty() {
+            return Layout {
+                zoom: 1.0,
+                offset_x: 0.0,
+                offset_y: 0.0,
+            };
+        }
+
+        let min_x = rects.iter().map(|r| r.1).fold(f64::MAX, f64::min);
+        let min_y = rects.iter().map(|r| r.2).fold(f64::MAX, f64::min);
+        let max_x = rects.iter().map(|r| r.1 + r.3).fold(f64::MIN, f64::max);
+        let max_y = rects.iter().map(|r| r.2 + r.4).fold(f64::MIN, f64::max);
+
+        let span_x = (max_x - min_x).max(MIN_MONITOR_SIZE);
+        let span_y = (max_y - min_y).max(MIN_MONITOR_SIZE);
+
+        let margin = 20.0;
+        let zoom = ((width - 2.0 * margin) / span_x)
+            .min((height - 2.0 * margin) / span_y)
+            .max(0.01);
+        Layout {
+            zoom,
+            offset_x: margin - min_x * zoom + (width - 2.0 * margin - span_x * zoom) / 2.0,
+            offset_y: margin - min_y * zoom + (height - 2.0 * margin - span_y * zoom) / 2.0,
+        }
+    }
+
+    fn current_layout(&self) -> Layout {
+        if let Some(drag) = self.drag.borrow().as_ref() {
+            return Layout {
+                zoom: drag.layout.zoom,
+                offset_x: drag.layout.offset_x,
+                offset_y: drag.layout.offset_y,
+            };
+        }
+        self.compute_layout(self.canvas.width() as f64, self.canvas.height() as f64)
+    }
+
+    fn draw(&self, cr: &cairo::Context, width: f64, height: f64) {
+        let layout = self.current_layout();
+        let dragged = self.drag.borrow().as_ref().map(|d| d.index);
+        let monitors = self.monitors.borrow();
+
+        for (index, x, y, w, h) in self.layout_rects() {
+            let sx = layout.offset_x + x * layout.zoom;
+            let sy = layout.offset_y + y * layout.zoom;
+            let sw = w * layout.zoom;
+            let sh = h * layout.zoom;
+
+            cr.rectangle(sx, sy, sw, sh);
+            if dragged == Some(index) {
+                cr.set_source_rgba(0.35, 0.55, 0.85, 0.8);
+            } else {
+                cr.set_source_rgba(0.35, 0.55, 0.85, 0.5);
+            }
+            let _ = cr.fill_preserve();
+            cr.set_source_rgb(0.2, 0.35, 0.6);
+            cr.set_line_width(2.0);
+            let _ = cr.stroke();
+
+            let monitor = &monitors[index].monitor;
+            let name = if monitor.name.is_empty() {
+                "(any)"
+            } else {
+                monitor.name.as_str()
+            };
+            cr.set_source_rgb(1.0, 1.0, 1.0);
+            cr.set_font_size(14.0);
+            cr.move_to(sx + 8.0, sy + 20.0);
+            let _ = cr.show_text(name);
+            cr.set_font_size(11.0);
+            cr.move_to(sx + 8.0, sy + 36.0);
+            let _ = cr.show_text(&format!("{} @ {}", monitor.resolution, monitor.position));
+        }
+
+        if monitors.is_empty() {
+            cr.set_source_rgba(0.5, 0.5, 0.5, 1.0);
+            cr.set_font_size(14.0);
+            cr.move_to(width / 2.0 - 60.0, height / 2.0);
+            let _ = cr.show_text("No monitors defined");
+        }
+    }
+
+    fn drag_begin(&self, x: f64, y: f64) {
+        let layout = self.current_layout();
+        let hit = self
+            .layout_rects()
+            .into_iter()
+            .rev()
+            .find(|(_, mx, my, w, h)| {
+                let sx = layout.offset_x + mx * layout.zoom;
+                let sy = layout.offset_y + my * layout.zoom;
+                x >= sx && x <= sx + w * layout.zoom && y >= sy && y <= sy + h * layout.zoom
+            });
+
+        if let Some((index, mx, my, _, _)) = hit {
+            *self.drag.borrow_mut() = Some(DragState {
+                index,
+                start: (mx, my),
+                layout,
+            });
+        }
+    }
+
+    fn drag_update(&self, offset_x: f64, offset_y: f64) {
+        let (index, start, zoom) = match self.drag.borrow().as_ref() {
+            Some(drag) => (drag.index, drag.start, drag.layout.zoom),
+            None => return,
+        };
+
+        let rects = self.layout_rects();
+        let (width, height) = match rects.iter().find(|r| r.0 == index) {
+            Some(rect) => (rect.3, rect.4),
+            None => return,
+        };
+
+        let mut x = start.0 + offset_x / zoom;
+        let mut y = start.1 + offset_y / zoom;
+        let threshold = 15.0 / zoom;
+
+        for (other, ox, oy, ow, oh) in &rects {
+            if *other == index {
+                continue;
+            }
+            for candidate in [ox + ow, ox - width, *ox, ox + ow - width] {
+                if (x - candidate).abs() < threshold {
+                    x = candidate;
+                }
+            }
+            for candidate in [oy + oh, oy - height, *oy, oy + oh - height] {
+                if (y - candidate).abs() < threshold {
+                    y = candidate;
+                }
+            }
+        }
+
+        let position = format!("{}x{}", x.round() as i64, y.round() as i64);
+        if let Some(entry) = self.position_entries.borrow().get(index) {
+            entry.set_text(&position);
+        }
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let monitor = self.monitors.borrow()[index].monitor.clone();
+
+        let vbox = Box::new(Orientation::Vertical, 5);
+        vbox.set_margin_start(5);
+        vbox.set_margin_end(5);
+        vbox.set_margin_top(5);
+        vbox.set_margin_bottom(5);
+
+        let main_box = Box::new(Orientation::Horizontal, 5);
+        let options_box = Box::new(Orientation::Horizontal, 5);
+
+        let name_entry = Entry::new();
+        name_entry.set_placeholder_text(Some("Name (e.g. DP-1, desc:...)"));
+        name_entry.set_text(&monitor.name);
+        name_entry.set_hexpand(true);
+
+        let resolution_entry = Entry::new();
+        resolution_entry.set_placeholder_text(Some("1920x1080@60"));
+        resolution_entry.set_text(&monitor.resolution);
+
+        let position_entry = Entry::new();
+        position_entry.set_placeholder_text(Some("0x0 or auto"));
+        position_entry.set_text(&monitor.position);
+        position_entry.set_width_chars(10);
+        self.position_entries
+            .borrow_mut()
+            .push(position_entry.clone());
+
+        let scale_entry = Entry::new();
+        scale_entry.set_placeholder_text(Some("Scale"));
+        scale_entry.set_text(&monitor.scale);
+        scale_entry.set_width_chars(5);
+
+        let disabled_check = CheckButton::with_label("Disabled");
+        disabled_check.set_active(monitor.disabled);
+
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        main_box.append(&name_entry);
+        main_box.append(&resolution_entry);
+        main_box.append(&position_entry);
+        main_box.append(&scale_entry);
+        main_box.append(&disabled_check);
+        main_box.append(&delete_button);
+
+        let mirror_entry = Entry::new();
+        mirror_entry.set_placeholder_text(Some("Mirror output"));
+        mirror_entry.set_text(&monitor.mirror);
+
+        options_box.append(&mirror_entry);
+        let transform_dropdown =
+            add_choice(&options_box, "Transform", &TRANSFORMS, &monitor.transform);
+        let bitdepth_dropdown =
+            add_choice(&options_box, "Bit depth", &BITDEPTHS, &monitor.bitdepth);
+        let vrr_dropdown = add_choice(&options_box, "VRR", &VRR_MODES, &monitor.vrr);
+        let cm_dropdown = add_choice(&options_box, "Color", &COLOR_MODES, &monitor.cm);
+
+        vbox.append(&main_box);
+        vbox.append(&options_box);
+
+        let page = self.clone();
+        name_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |m| m.name = text.clone());
+        });
+
+        let page = self.clone();
+        resolution_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |m| m.resolution = text.clone());
+        });
+
+        let page = self.clone();
+        position_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |m| m.position = text.clone());
+        });
+
+        let page = self.clone();
+        scale_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |m| m.scale = text.clone());
+        });
+
+        let page = self.clone();
+        mirror_entry.connect_changed(move |entry| {
+            let text = entry.text().to_string();
+            page.update(index, |m| m.mirror = text.clone());
+        });
+
+        let page = self.clone();
+        disabled_check.connect_toggled(move |check| {
+            let active = check.is_active();
+            page.update(index, |m| {
+                m.disabled = active;
+                if !active && m.resolution.is_empty() {
+                    m.resolution = "preferred".to_string();
+                    m.position = "auto".to_string();
+                    m.scale = "1".to_string();
+                }
+            });
+        });
+
+        for (dropdown, field) in [
+            (transform_dropdown, 0),
+            (bitdepth_dropdown, 1),
+            (vrr_dropdown, 2),
+            (cm_dropdown, 3),
+        ] {
+            let page = self.clone();
+            dropdown.connect_selected_notify(move |dd| {
+                let value = dd
+                    .selected_item()
+                    .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+                    .map(|item| item.string().to_string())
+                    .unwrap_or_default();
+                page.update(index, |m| match field {
+                    0 => m.transform = value.clone(),
+                    1 => m.bitdepth = value.clone(),
+                    2 => m.vrr = value.clone(),
+                    _ => m.cm = value.clone(),
+                });
+            });
+        }
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.monitors.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        vbox
+    }
+}
+
+fn add_choice(container: &Box, label: &str, items: &[&str], value: &str) -> DropDown {
+    let label_widget = Label::new(Some(label));
+    label_widget.set_opacity(0.7);
+
+    let mut items = items.to_vec();
+    if !items.contains(&value) {
+        items.push(value);
+    }
+    let dropdown = DropDown::new(Some(StringList::new(&items)), None::<gtk::Expression>);
+    if let Some(position) = items.iter().position(|item| *item == value) {
+        dropdown.set_selected(position as u32);
+    }
+
+    container.append(&label_widget);
+    container.append(&dropdown);
+    dropdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips_monitor_lines() {
+        for line in [
+            "DP-1, 2560x1440@144, 0x0, 1",
+            "DP-1, 2560x1440@144, 0x0, 1.25, transform, 1, vrr, 2",
+            "eDP-1, preferred, auto, 1, mirror, DP-1",
+            "HDMI-A-1, highres, auto-right, 2, bitdepth, 10, cm, hdr, sdrbrightness, 1.2",
+            "HDMI-A-1, disable",
+        ] {
+            assert_eq!(Monitor::parse(line).value(), line);
+        }
+    }
+
+    #[test]
+    fn parse_fills_in_missing_fields() {
+        let monitor = Monitor::parse("DP-2");
+        assert_eq!(monitor.resolution, "preferred");
+        assert_eq!(monitor.position, "auto");
+        assert_eq!(monitor.scale, "1");
+        assert_eq!(monitor.value(), "DP-2, preferred, auto, 1");
+    }
+
+    #[test]
+    fn parse_reads_optional_fields() {
+        let monitor = Monitor::parse("DP-1, 1920x1080, 0x0, 1, vrr, 1, foo, bar");
+        assert_eq!(monitor.vrr, "1");
+        assert_eq!(monitor.extra, vec![("foo".to_string(), "bar".to_string())]);
+        assert!(monitor.is_layout());
+        assert!(Monitor::parse("DP-1, disabled").disabled);
+    }
+
+    #[test]
+    fn block_entries_round_trip() {
+        let monitor = Monitor::parse("DP-1, 2560x1440@144, 0x0, 1.25, transform, 1");
+        assert_eq!(Monitor::parse_block(&monitor.block_entries()), monitor);
+    }
+}
@@ -0,0 +1,284 @@
+pub const WINDOW_RULE_KEYWORDS: [&str; 2] = ["windowrule", "windowrulev2"];
+
+pub const WINDOW_RULE_EFFECTS: &[(&str, &str)] = &[
+    ("float", ""),
+    ("tile", ""),
+    ("fullscreen", ""),
+    ("maximize", ""),
+    ("pin", ""),
+    ("center", "[1]"),
+    ("size", "width height"),
+    ("minsize", "width height"),
+    ("maxsize", "width height"),
+    ("move", "x y"),
+    ("opacity", "active [inactive] [fullscreen]"),
+    ("workspace", "workspace [silent]"),
+    ("monitor", "monitor"),
+    ("pseudo", ""),
+    ("noblur", ""),
+    ("noshadow", ""),
+    ("noborder", ""),
+    ("nodim", ""),
+    ("noanim", ""),
+    ("nofocus", ""),
+    ("noinitialfocus", ""),
+    ("noshortcutsinhibit", ""),
+    ("nomaxsize", ""),
+    ("norounding", ""),
+    ("rounding", "pixels"),
+    ("bordersize", "pixels"),
+    ("bordercolor", "active [inactive]"),
+    ("animation", "style"),
+    ("dimaround", ""),
+    ("keepaspectratio", ""),
+    ("stayfocused", ""),
+    ("focusonactivate", "[0/1]"),
+    ("xray", "0/1"),
+    ("immediate", ""),
+    ("idleinhibit", "none/always/focus/fullscreen"),
+    (
+        "suppressevent",
+        "fullscreen maximize activate activatefocus",
+    ),
+    ("tag", "[+/-]name"),
+    ("group", "set/new/deny/lock ..."),
+    ("renderunfocused", ""),
+    ("syncfullscreen", "[0/1]"),
+    ("fullscreenstate", "internal client"),
+    ("persistentsize", ""),
+    ("forcergbx", ""),
+    ("allowsinput", ""),
+    ("scrollmouse", "factor"),
+    ("scrolltouchpad", "factor"),
+    ("content", "none/photo/video/game"),
+];
+
+pub const WINDOW_RULE_MATCHERS: &[(&str, &str)] = &[
+    ("class", "regex"),
+    ("title", "regex"),
+    ("initialClass", "regex"),
+    ("initialTitle", "regex"),
+    ("tag", "name"),
+    ("xwayland", "0/1"),
+    ("floating", "0/1"),
+    ("fullscreen", "0/1"),
+    ("pinned", "0/1"),
+    ("focus", "0/1"),
+    ("group", "0/1"),
+    ("modal", "0/1"),
+    ("workspace", "id or name:..."),
+    ("onworkspace", "id, name:... or selector"),
+    ("fullscreenstate", "internal client"),
+    ("content", "none/photo/video/game"),
+    ("xdgTag", "tag"),
+];
+
+pub fn is_window_rule_keyword(keyword: &str) -> bool {
+    WINDOW_RULE_KEYWORDS.contains(&keyword)
+}
+
+fn is_matcher(part: &str) -> bool {
+    part.split_once(':').is_some_and(|(key, _)| {
+        WINDOW_RULE_MATCHERS
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case(key.trim()))
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RuleSyntax {
+    Legacy,
+    Matchers,
+    Block,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowRule {
+    pub syntax: RuleSyntax,
+    pub name: String,
+    pub effects: Vec<(String, String)>,
+    pub matchers: Vec<(String, String)>,
+}
+
+impl WindowRule {
+    pub fn new(syntax: RuleSyntax) -> Self {
+        Self {
+            syntax,
+            name: String::new(),
+            effects: vec![("float".to_string(), String::new())],
+            matchers: vec![("class".to_string(), String::new())],
+        }
+    }
+
+    pub fn parse(keyword: &str, value: &str) -> Self {
+        let mut parts = value.split(',');
+        let effect = parts.next().unwrap_or_default().trim();
+        let (effect, args) = effect
+            .split_once(char::is_whitespace)
+            .unwrap_or((effect, ""));
+        let rest: Vec<&str> = parts.collect();
+
+        if keyword == "windowrule" && rest.len() == 1 && !is_matcher(rest[0].trim()) {
+            return Self {
+                syntax: RuleSyntax::Legacy,
+                name: String::new(),
+                effects: vec![(effect.to_string(), args.trim().to_string())],
+                matchers: vec![("class".to_string(), rest[0].trim().to_string())],
+            };
+        }
+
+        let mut matchers: Vec<(String, String)> = Vec::new();
+        for part in rest {
+            match part.trim().split_once(':') {
+                Some((key, value)) if is_matcher(part.trim()) => {
+                    matchers.push((key.trim().to_string(), value.trim().to_string()))
+                }
+                _ => match matchers.last_mut() {
+                    Some(last) => last.1 = format!("{},{}", last.1, part.trim_end()),
+                    None => matchers.push((part.trim().to_string(), String::new())),
+                },
+            }
+        }
+
+        Self {
+            syntax: RuleSyntax::Matchers,
+            name: String::new(),
+            effects: vec![(effect.to_string(), args.trim().to_string())],
+            matchers,
+        }
+    }
+
+    pub fn parse_block(entries: &[(String, String)]) -> Self {
+        let mut rule = Self {
+            syntax: RuleSyntax::Block,
+            name: String::new(),
+            effects: Vec::new(),
+            matchers: Vec::new(),
+        };
+        for (key, value) in entries {
+            if key == "name" {
+                rule.name = value.clone();
+            } else if let Some(matcher) = key.strip_prefix("match:") {
+                rule.matchers.push((matcher.to_string(), value.clone()));
+            } else {
+                rule.effects.push((key.clone(), value.clone()));
+            }
+        }
+        rule
+    }
+
+    pub fn value(&self) -> String {
+        let (effect, args) = self.effects.first().cloned().unwrap_or_default();
+        let effect = if args.is_empty() {
+            effect
+        } else {
+            format!("{} {}", effect, args)
+        };
+
+        if self.syntax == RuleSyntax::Legacy
+            && self.matchers.len() == 1
+            && self.matchers[0].0 == "class"
+        {
+            return format!("{}, {}", effect, self.matchers[0].1);
+        }
+
+        let mut parts = vec![effect];
+        for (key, value) in &self.matchers {
+            parts.push(format!("{}:{}", key, value));
+        }
+        parts.join(", ")
+    }
+
+    pub fn block_entries(&self) -> Vec<(String, String)> {
+        let mut entries = Vec::new();
+        if !self.name.is_empty() {
+            entries.push(("name".to_string(), self.name.clone()));
+        }
+        for (key, value) in &self.matchers {
+            entries.push((format!("match:{}", key), value.clone()));
+        }
+        for (effect, args) in &self.effects {
+            let args = if args.is_empty() { "on" } else { args.as_str() };
+            entries.push((effect.clone(), args.to_string()));
+        }
+        entries
+    }
+}
+
+pub fn effect_placeholder(effect: &str) -> &'static str {
+    WINDOW_RULE_EFFECTS
+        .iter()
+        .find(|(name, _)| *name == effect)
+        .map(|(_, placeholder)| *placeholder)
+        .unwrap_or("arguments")
+}
+
+pub fn matcher_placeholder(matcher: &str) -> &'static str {
+    WINDOW_RULE_MATCHERS
+        .iter()
+        .find(|(name, _)| *name == matcher)
+        .map(|(_, placeholder)| *placeholder)
+        .unwrap_or("value")
+}
+
+pub const LAYER_RULE_EFFECTS: [&str; 9] = [
+    "blur",
+    "ignorealpha",
+    "ignorezero",
+    "noanim",
+    "animation",
+    "order",
+    "xray",
+    "dimaround",
+    "abovelock",
+];
+
+pub const LAYER_ANIMATION_STYLES: [&str; 7] = [
+    "slide",
+    "slide top",
+    "slide bottom",
+    "slide left",
+    "slide right",
+    "popin",
+    "fade",
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayerRule {
+    pub effect: String,
+    pub args: String,
+    pub namespace: String,
+}
+
+impl LayerRule {
+    pub fn parse(value: &str) -> Self {
+        let (effect, namespace) = value.split_once(',').unwrap_or((value, ""));
+        let effect = effect.trim();
+        let (effect, args) = effect
+            .split_once(char::is_whitespace)
+            .unwrap_or((effect, ""));
+        Self {
+            effect: effect.to_string(),
+            args: args.trim().to_string(),
+            namespace: namespace.trim().to_string(),
+        }
+    }
+
+    pub fn value(&self) -> String {
+        if self.args.is_empty() {
+            format!("{}, {}", self.effect, self.namespace)
+        } else {
+            format!("{} {}, {}", self.effect, self.args, self.namespace)
+        }
+    }
+
+    pub fn default_args(effect: &str) -> &'static str {
+        match effect {
+            "ignorealpha" => "0.5",
+            "animation" => "slide",
+            "order" => "0",
+            "xray" => "1",
+            _ => "",
+        }
+    }
+}
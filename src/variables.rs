@@ -0,0 +1,473 @@
+use gtk::{
+    prelude::*, Box, Button, Entry, Label, ListBox, Orientation, ScrolledWindow, SelectionMode,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::widgets::WidgetBuilder;
+
+pub fn is_variable_keyword(keyword: &str) -> bool {
+    keyword.len() > 1 && keyword.starts_with('$')
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(is_name_char)
+}
+
+fn find_variable<'a>(
+    text: &str,
+    variables: &'a [(String, String)],
+) -> Option<&'a (String, String)> {
+    let identifier: String = text.chars().take_while(|c| is_name_char(*c)).collect();
+    variables
+        .iter()
+        .filter(|(name, _)| name.len() > 1 && identifier.starts_with(&name[1..]))
+        .max_by_key(|(name, _)| name.len())
+}
+
+pub fn resolve(value: &str, variables: &[(String, String)]) -> String {
+    let mut resolved = String::new();
+    let mut rest = value;
+    while let Some(position) = rest.find('$') {
+        resolved.push_str(&rest[..position]);
+        let after = &rest[position + 1..];
+        match find_variable(after, variables) {
+            Some((name, value)) => {
+                resolved.push_str(value);
+                rest = &after[name.len() - 1..];
+            }
+            None => {
+                resolved.push('$');
+                rest = after;
+            }
+        }
+    }
+    resolved.push_str(rest);
+    resolved
+}
+
+pub fn references(value: &str, variables: &[(String, String)]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = value;
+    while let Some(position) = rest.find('$') {
+        let after = &rest[position + 1..];
+        match find_variable(after, variables) {
+            Some((name, _)) => {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+                rest = &after[name.len() - 1..];
+            }
+            None => rest = after,
+        }
+    }
+    names
+}
+
+pub fn has_expression(value: &str) -> bool {
+    value
+        .find("{{")
+        .is_some_and(|start| value[start..].contains("}}"))
+}
+
+pub fn evaluate_expressions(value: &str, variables: &[(String, String)]) -> Result<String, String> {
+    let mut evaluated = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .map(|end| end + start)
+            .ok_or_else(|| format!("Unterminated expression in '{}'", value))?;
+        evaluated.push_str(&resolve(&rest[..start], variables));
+        let expression = resolve(&rest[start + 2..end], variables);
+        evaluated.push_str(&format_result(evaluate(&expression)?));
+        rest = &rest[end + 2..];
+    }
+    evaluated.push_str(&resolve(rest, variables));
+    Ok(evaluated)
+}
+
+fn format_result(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        let rounded = (value * 1_000_000.0).round() / 1_000_000.0;
+        format!("{}", rounded)
+    }
+}
+
+fn evaluate(expression: &str) -> Result<f64, String> {
+    let mut parser = ExpressionParser {
+        chars: expression.chars().filter(|c| !c.is_whitespace()).collect(),
+        position: 0,
+    };
+    let value = parser.sum()?;
+    match parser.peek() {
+        Some(c) => Err(format!(
+            "Unexpected '{}' in expression '{}'",
+            c,
+            expression.trim()
+        )),
+        None => Ok(value),
+    }
+}
+
+struct ExpressionParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl ExpressionParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn sum(&mut self) -> Result<f64, String> {
+        let mut value = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.position += 1;
+            let rhs = self.product()?;
+            if op == '+' {
+                value += rhs;
+            } else {
+                value -= rhs;
+            }
+        }
+        Ok(value)
+    }
+
+    fn product(&mut self) -> Result<f64, String> {
+        let mut value = self.factor()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.position += 1;
+            let rhs = self.factor()?;
+            if op == '*' {
+                value *= rhs;
+            } else if rhs == 0.0 {
+                return Err("Division by zero".to_string());
+            } else {
+                value /= rhs;
+            }
+        }
+        Ok(value)
+    }
+
+    fn factor(&mut self) -> Result<f64, String> {
+        match self.peek() {
+            Some('-') => {
+                self.position += 1;
+                Ok(-self.factor()?)
+            }
+            Some('+') => {
+                self.position += 1;
+                self.factor()
+            }
+            Some('(') => {
+                self.position += 1;
+                let value = self.sum()?;
+                if self.peek() != Some(')') {
+                    return Err("Missing ')' in expression".to_string());
+                }
+                self.position += 1;
+                Ok(value)
+            }
+            _ => {
+                let start = self.position;
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.position += 1;
+                }
+                let number: String = self.chars[start..self.position].iter().collect();
+                number.parse().map_err(|_| match self.peek() {
+                    Some(c) => format!("Expected a number, found '{}'", c),
+                    None => "Expected a number".to_string(),
+                })
+            }
+        }
+    }
+}
+
+pub fn collect_values(config: &HyprlandConfig) -> Vec<(String, String)> {
+    resolve_definitions(&directives::collect(config, &is_variable_keyword))
+}
+
+fn resolve_definitions(definitions: &[Directive]) -> Vec<(String, String)> {
+    let mut variables: Vec<(String, String)> = Vec::new();
+    for definition in definitions {
+        let value = resolve(&definition.value, &variables);
+        match variables
+            .iter_mut()
+            .find(|(name, _)| *name == definition.keyword)
+        {
+            Some(existing) => existing.1 = value,
+            None => variables.push((definition.keyword.clone(), value)),
+        }
+    }
+    variables
+}
+
+#[derive(Clone)]
+pub struct VariablesPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    variables: Rc<RefCell<Vec<Directive>>>,
+    resolved_labels: Rc<RefCell<Vec<Label>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl VariablesPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Variables",
+            "Definitions referenced as $name by other options. Renaming one does not update its references.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Variable");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            variables: Rc::new(RefCell::new(Vec::new())),
+            resolved_labels: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            page_clone
+                .variables
+                .borrow_mut()
+                .push(Directive::new("$", "", 0, None));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.variables.borrow_mut() = directives::collect(config, &is_variable_keyword);
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &is_variable_keyword, &self.variables.borrow());
+        }
+    }
+
+    pub fn invalid_variables(&self) -> Vec<String> {
+        if !self.has_changes() {
+            return Vec::new();
+        }
+        self.variables
+            .borrow()
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| !d.keyword.strip_prefix('$').is_some_and(is_valid_name))
+            .map(|(index, d)| format!("row {}: {} = {}", index + 1, d.keyword, d.value))
+            .collect()
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+        self.resolved_labels.borrow_mut().clear();
+
+        let count = self.variables.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+
+        self.refresh_resolved();
+    }
+
+    fn refresh_resolved(&self) {
+        let variables = self.variables.borrow();
+        for (index, label) in self.resolved_labels.borrow().iter().enumerate() {
+            let resolved = resolve_definitions(&variables[..index]);
+            let value = resolve(&variables[index].value, &resolved);
+            if value == variables[index].value {
+                label.set_text("");
+            } else {
+                label.set_text(&format!("= {}", value));
+            }
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut Directive)) {
+        if let Some(directive) = self.variables.borrow_mut().get_mut(index) {
+            edit(directive);
+        }
+        *self.changed.borrow_mut() = true;
+        self.refresh_resolved();
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let directive = self.variables.borrow()[index].clone();
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+        hbox.set_margin_start(5);
+        hbox.set_margin_end(5);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let prefix_label = Label::new(Some("$"));
+
+        let name_entry = Entry::new();
+        name_entry.set_placeholder_text(Some("Name"));
+        name_entry.set_text(directive.keyword.trim_start_matches('$'));
+        name_entry.set_width_chars(16);
+        if !is_valid_name(directive.keyword.trim_start_matches('$')) {
+            name_entry.add_css_class("error");
+        }
+
+        let value_entry = Entry::new();
+        value_entry.set_placeholder_text(Some("Value"));
+        value_entry.set_text(&directive.value);
+        value_entry.set_hexpand(true);
+
+        let resolved_label = Label::new(None);
+        resolved_label.set_opacity(0.7);
+        resolved_label.set_max_width_chars(30);
+        resolved_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.variables.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&prefix_label);
+        hbox.append(&name_entry);
+        hbox.append(&value_entry);
+        hbox.append(&resolved_label);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        self.resolved_labels.borrow_mut().push(resolved_label);
+
+        let page = self.clone();
+        name_entry.connect_changed(move |entry| {
+            let name = entry.text().trim().to_string();
+            if !is_valid_name(&name) {
+                entry.add_css_class("error");
+            } else {
+                entry.remove_css_class("error");
+            }
+            page.update(index, |d| d.keyword = format!("${}", name));
+        });
+
+        let page = self.clone();
+        value_entry.connect_changed(move |entry| {
+            let value = entry.text().to_string();
+            page.update(index, |d| d.value = value.clone());
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.variables.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        hbox
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate_plain(value: &str) -> Result<String, String> {
+        evaluate_expressions(value, &[])
+    }
+
+    #[test]
+    fn expressions_follow_operator_precedence() {
+        assert_eq!(evaluate_plain("{{2 + 3 * 4}}").unwrap(), "14");
+        assert_eq!(evaluate_plain("{{(2 + 3) * 4}}").unwrap(), "20");
+        assert_eq!(evaluate_plain("{{10 - 4 - 3}}").unwrap(), "3");
+        assert_eq!(evaluate_plain("{{10 / 4}}").unwrap(), "2.5");
+    }
+
+    #[test]
+    fn expressions_support_unary_minus() {
+        assert_eq!(evaluate_plain("{{-3 + 5}}").unwrap(), "2");
+        assert_eq!(evaluate_plain("{{--2}}").unwrap(), "2");
+        assert_eq!(evaluate_plain("{{2 * -(1 + 1)}}").unwrap(), "-4");
+    }
+
+    #[test]
+    fn expressions_resolve_variables_and_keep_surrounding_text() {
+        let variables = vec![
+            ("$gap".to_string(), "5".to_string()),
+            ("$gaps".to_string(), "7".to_string()),
+        ];
+        assert_eq!(
+            evaluate_expressions("{{$gap * 2}}, $gaps", &variables).unwrap(),
+            "10, 7"
+        );
+    }
+
+    #[test]
+    fn invalid_expressions_are_errors() {
+        assert_eq!(evaluate_plain("{{1 / 0}}").unwrap_err(), "Division by zero");
+        assert!(evaluate_plain("{{(1 + 2}}").is_err());
+        assert!(evaluate_plain("{{1 +}}").is_err());
+        assert!(evaluate_plain("{{1 + 2").is_err());
+    }
+}
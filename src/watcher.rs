@@ -0,0 +1,133 @@
+use gtk::{gio, prelude::*, Box, Button, Label, Orientation, Revealer};
+use std::cell::RefCell;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct ConfigWatcher {
+    pub revealer: Revealer,
+    pub reload_button: Button,
+    pub merge_button: Button,
+    message: Label,
+    monitors: Rc<RefCell<Vec<gio::FileMonitor>>>,
+    files: Rc<RefCell<Vec<(PathBuf, String)>>>,
+    failures: Rc<RefCell<Vec<String>>>,
+}
+
+impl ConfigWatcher {
+    pub fn new() -> Self {
+        let revealer = Revealer::new();
+        revealer.set_transition_type(gtk::RevealerTransitionType::SlideDown);
+
+        let hbox = Box::new(Orientation::Horizontal, 10);
+        hbox.set_margin_start(10);
+        hbox.set_margin_end(10);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let message = Label::new(None);
+        message.set_halign(gtk::Align::Start);
+        message.set_hexpand(true);
+        message.set_wrap(true);
+
+        let reload_button = Button::with_label("Reload");
+        let merge_button = Button::with_label("Merge");
+        merge_button.add_css_class("suggested-action");
+        let dismiss_button = Button::from_icon_name("window-close-symbolic");
+        dismiss_button.set_has_frame(false);
+
+        hbox.append(&message);
+        hbox.append(&reload_button);
+        hbox.append(&merge_button);
+        hbox.append(&dismiss_button);
+
+        let frame = gtk::Frame::new(None);
+        frame.set_child(Some(&hbox));
+        revealer.set_child(Some(&frame));
+
+        let revealer_clone = revealer.clone();
+        dismiss_button.connect_clicked(move |_| {
+            revealer_clone.set_reveal_child(false);
+        });
+
+        Self {
+            revealer,
+            reload_button,
+            merge_button,
+            message,
+            monitors: Rc::new(RefCell::new(Vec::new())),
+            files: Rc::new(RefCell::new(Vec::new())),
+            failures: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn watch(&self, paths: Vec<PathBuf>, on_change: impl Fn() + Clone + 'static) {
+        let mut monitors = self.monitors.borrow_mut();
+        for monitor in monitors.drain(..) {
+            monitor.cancel();
+        }
+
+        let mut files = self.files.borrow_mut();
+        files.clear();
+        let mut failures = self.failures.borrow_mut();
+        failures.clear();
+        for path in paths {
+            let content = fs::read_to_string(&path).unwrap_or_default();
+            let target = fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+            match gio::File::for_path(&target)
+                .monitor_file(gio::FileMonitorFlags::WATCH_MOVES, gio::Cancellable::NONE)
+            {
+                Ok(monitor) => {
+                    let on_change = on_change.clone();
+                    monitor.connect_changed(move |_, _, _, event| {
+                        if event != gio::FileMonitorEvent::AttributeChanged {
+                            on_change();
+                        }
+                    });
+                    monitors.push(monitor);
+                }
+                Err(e) => failures.push(format!("{}: {}", path.display(), e)),
+            }
+            files.push((path, content));
+        }
+
+        drop(failures);
+        self.hide();
+    }
+
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        self.files.borrow().clone()
+    }
+
+    pub fn changed_files(&self) -> Vec<PathBuf> {
+        self.files
+            .borrow()
+            .iter()
+            .filter(|(path, content)| fs::read_to_string(path).unwrap_or_default() != *content)
+            .map(|(path, _)| path.clone())
+            .collect()
+    }
+
+    pub fn show(&self, text: &str, can_merge: bool) {
+        self.message.set_text(text);
+        self.reload_button.set_visible(true);
+        self.merge_button.set_visible(can_merge);
+        self.revealer.set_reveal_child(true);
+    }
+
+    pub fn hide(&self) {
+        let failures = self.failures.borrow();
+        if failures.is_empty() {
+            self.revealer.set_reveal_child(false);
+            return;
+        }
+        self.message.set_text(&format!(
+            "Changes made outside hyprgui will not be detected for:\n{}",
+            failures.join("\n")
+        ));
+        self.reload_button.set_visible(false);
+        self.merge_button.set_visible(false);
+        self.revealer.set_reveal_child(true);
+    }
+}
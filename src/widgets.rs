@@ -1,13 +1,178 @@
 use gtk::{
-    gdk, prelude::*, Box, Button, ColorButton, Entry, Frame, Image, Label, Orientation, Popover,
-    SpinButton, Switch, Widget,
+    gdk, prelude::*, Box, Button, ColorButton, DropDown, Entry, Frame, Image, Label, MessageDialog,
+    Orientation, Popover, SpinButton, StringList, Switch, Widget,
 };
 use hyprparser::HyprlandConfig;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 
-use crate::gui::get_option_limits;
+use crate::defaults;
+use crate::directives;
+use crate::gui::{get_option_limits, option_path};
+use crate::history::History;
+use crate::variables;
+
+#[derive(Clone)]
+struct OptionState {
+    key: (String, String),
+    default: Option<&'static str>,
+    origin: Option<(usize, String)>,
+    in_file: bool,
+    explicit: Rc<Cell<bool>>,
+    status_label: Label,
+    reset_button: Button,
+    bound_button: Rc<RefCell<Option<Button>>>,
+    shown: Rc<RefCell<String>>,
+    changed_options: Rc<RefCell<HashMap<(String, String), String>>>,
+    history: History,
+}
+
+impl OptionState {
+    fn new(
+        widget: &Widget,
+        key: (String, String),
+        default: Option<&'static str>,
+        in_file: bool,
+        origin: Option<(usize, String)>,
+        changed_options: Rc<RefCell<HashMap<(String, String), String>>>,
+        history: History,
+    ) -> Option<Self> {
+        let row = widget.parent().and_downcast::<Box>()?;
+
+        let status_label = Label::new(None);
+        status_label.set_opacity(0.7);
+        row.insert_child_after(&status_label, widget.prev_sibling().as_ref());
+
+        let reset_button = Button::from_icon_name("edit-undo-symbolic");
+        reset_button.set_has_frame(false);
+        reset_button.set_valign(gtk::Align::Center);
+        reset_button.set_tooltip_text(Some("Reset to default and remove the line from the config"));
+        row.append(&reset_button);
+
+        let state = Self {
+            key,
+            default,
+            origin,
+            in_file,
+            explicit: Rc::new(Cell::new(in_file)),
+            status_label,
+            reset_button,
+            bound_button: Rc::new(RefCell::new(None)),
+            shown: Rc::new(RefCell::new(String::new())),
+            changed_options,
+            history,
+        };
+        state.set_explicit(in_file);
+        Some(state)
+    }
+
+    fn set_explicit(&self, explicit: bool) {
+        self.explicit.set(explicit);
+        let status = match (explicit, &self.origin) {
+            (true, Some((file, origin))) if *file > 0 => format!("from {}", origin),
+            (true, _) => String::new(),
+            (false, _) => match self.default {
+                Some(default) => format!("not set (default {})", default),
+                None => "not set".to_string(),
+            },
+        };
+        self.status_label.set_visible(!status.is_empty());
+        self.status_label.set_text(&status);
+        self.reset_button
+            .set_opacity(if explicit { 1.0 } else { 0.0 });
+        self.reset_button.set_sensitive(explicit);
+    }
+
+    fn show_default(&self, widget: &Widget) {
+        match widget.downcast_ref::<Entry>() {
+            Some(entry) => {
+                entry.set_text("");
+                entry.set_placeholder_text(self.default);
+            }
+            None => {
+                if let Some(default) = self.default {
+                    WidgetBuilder::set_value(widget, default);
+                }
+            }
+        }
+    }
+
+    fn record(&self, value: String) {
+        let before = self.changed_options.borrow().get(&self.key).cloned();
+        let shown_before = self.shown.replace(value.clone());
+        let unset = value.is_empty()
+            || (!self.explicit.get()
+                && self
+                    .default
+                    .is_some_and(|default| defaults::is_default_value(&value, default)));
+
+        if unset {
+            self.clear();
+        } else {
+            self.changed_options
+                .borrow_mut()
+                .insert(self.key.clone(), value.clone());
+            self.set_explicit(true);
+        }
+
+        let after = self.changed_options.borrow().get(&self.key).cloned();
+        self.history
+            .push(self.key.clone(), before, after, shown_before, value);
+    }
+
+    fn clear(&self) {
+        let mut changes = self.changed_options.borrow_mut();
+        if self.in_file {
+            changes.insert(self.key.clone(), String::new());
+        } else {
+            changes.remove(&self.key);
+        }
+        drop(changes);
+        self.set_explicit(false);
+    }
+
+    fn reset(&self, widget: &Widget) {
+        let before = self.changed_options.borrow().get(&self.key).cloned();
+        let shown_before = self.shown.borrow().clone();
+        self.history.suppress(|| {
+            self.set_explicit(false);
+            if let Some(bound_button) = self.bound_button.borrow_mut().take() {
+                if let Some(row) = bound_button.parent().and_downcast::<Box>() {
+                    row.remove(&bound_button);
+                }
+                widget.set_sensitive(true);
+            }
+            self.show_default(widget);
+            self.clear();
+        });
+        self.shown.replace(String::new());
+
+        let after = self.changed_options.borrow().get(&self.key).cloned();
+        self.history
+            .push(self.key.clone(), before, after, shown_before, String::new());
+    }
+
+    fn restore(&self, widget: &Widget, shown: &str, value: Option<String>) {
+        if shown.is_empty() {
+            self.show_default(widget);
+        } else {
+            WidgetBuilder::set_value(widget, shown);
+        }
+        self.shown.replace(shown.to_string());
+
+        let explicit = value
+            .as_ref()
+            .map_or(self.in_file, |value| !value.is_empty());
+        let mut changes = self.changed_options.borrow_mut();
+        match value {
+            Some(value) => changes.insert(self.key.clone(), value),
+            None => changes.remove(&self.key),
+        };
+        drop(changes);
+        self.set_explicit(explicit);
+    }
+}
 
 pub struct WidgetBuilder {
     pub options: HashMap<String, Widget>,
@@ -165,6 +330,61 @@ impl WidgetBuilder {
         options.insert(name.to_string(), switch.upcast());
     }
 
+    pub fn add_choice_option(
+        container: &Box,
+        options: &mut HashMap<String, Widget>,
+        name: &str,
+        label: &str,
+        description: &str,
+        choices: &[&str],
+    ) {
+        let hbox = Box::new(Orientation::Horizontal, 10);
+        hbox.set_margin_start(10);
+        hbox.set_margin_end(10);
+        hbox.set_margin_top(5);
+        hbox.set_margin_bottom(5);
+
+        let label_box = Box::new(Orientation::Horizontal, 5);
+        label_box.set_hexpand(true);
+
+        let label_widget = Label::new(Some(label));
+        label_widget.set_halign(gtk::Align::Start);
+
+        let tooltip_button = Button::new();
+        let question_mark_icon = Image::from_icon_name("dialog-question-symbolic");
+        tooltip_button.set_child(Some(&question_mark_icon));
+        tooltip_button.set_has_frame(false);
+
+        let popover = Popover::new();
+        let description_label = Label::new(Some(description));
+        description_label.set_margin_top(5);
+        description_label.set_margin_bottom(5);
+        description_label.set_margin_start(5);
+        description_label.set_margin_end(5);
+        popover.set_child(Some(&description_label));
+        popover.set_position(gtk::PositionType::Right);
+
+        tooltip_button.connect_clicked(move |button| {
+            popover.set_parent(button);
+            popover.popup();
+        });
+
+        label_box.append(&label_widget);
+        label_box.append(&tooltip_button);
+
+        let dropdown = DropDown::new(Some(StringList::new(choices)), None::<gtk::Expression>);
+        dropdown.set_halign(gtk::Align::End);
+        dropdown.set_valign(gtk::Align::Center);
+        dropdown.set_width_request(100);
+
+        hbox.append(&label_box);
+        hbox.append(&dropdown);
+
+        container.append(&hbox);
+
+        options.insert(name.to_string(), dropdown.upcast());
+    }
+
     pub fn add_float_option(
         container: &Box,
         options: &mut HashMap<String, Widget>,
@@ -330,49 +550,129 @@ impl WidgetBuilder {
         config: &HyprlandConfig,
         category: &str,
         changed_options: Rc<RefCell<HashMap<(String, String), String>>>,
+        history: History,
     ) {
+        let variables = variables::collect_values(config);
+        let files = directives::file_labels(config);
         for (name, widget) in &self.options {
-            let value = self.extract_value(config, category, name);
+            let (section, key) = option_path(category, name);
+            let mut raw_value = self.extract_value(config, category, name);
+            if raw_value.is_empty() {
+                if let Some((file, line)) = directives::find_option(config, &section, &key) {
+                    if let Some((_, value)) =
+                        directives::parse_line(&directives::file_lines(config, file)[line])
+                    {
+                        raw_value = value;
+                    }
+                }
+            }
+            let default = defaults::option_default(category, name);
+            let origin = if raw_value.is_empty() {
+                None
+            } else {
+                directives::find_option(config, &section, &key).map(|(file, line)| {
+                    let label = files.get(file).map_or("hyprland.conf", String::as_str);
+                    (file, format!("{}:{}", label, line + 1))
+                })
+            };
+            let state = match OptionState::new(
+                widget,
+                (category.to_string(), name.to_string()),
+                default,
+                !raw_value.is_empty(),
+                origin,
+                changed_options.clone(),
+                history.clone(),
+            ) {
+                Some(state) => state,
+                None => continue,
+            };
+
+            let evaluated = variables::evaluate_expressions(&raw_value, &variables);
+            let value = if raw_value.is_empty() {
+                String::new()
+            } else {
+                evaluated
+                    .clone()
+                    .unwrap_or_else(|_| variables::resolve(&raw_value, &variables))
+            };
+
+            let detach_state = state.clone();
+            let detached_value = evaluated.clone().ok();
+            let detach = move || {
+                if let Some(value) = &detached_value {
+                    detach_state.record(value.clone());
+                }
+            };
+
+            if variables::has_expression(&raw_value) {
+                let (label, tooltip, confirmation) = match &evaluated {
+                    Ok(value) => (
+                        format!("expression {}", raw_value),
+                        "Saving keeps the expression. Click to replace it with the evaluated value."
+                            .to_string(),
+                        format!(
+                            "The expression {} will be replaced by the fixed value {}.",
+                            raw_value, value
+                        ),
+                    ),
+                    Err(e) => (
+                        "invalid expression".to_string(),
+                        format!("{}: {}", raw_value, e),
+                        format!(
+                            "The expression {} will be replaced by the value you enter.",
+                            raw_value
+                        ),
+                    ),
+                };
+                *state.bound_button.borrow_mut() =
+                    Self::bind_value(widget, &label, &tooltip, Some(confirmation), detach);
+            } else {
+                let references = variables::references(&raw_value, &variables);
+                if !references.is_empty() {
+                    *state.bound_button.borrow_mut() = Self::bind_value(
+                        widget,
+                        &format!("bound to {}", references.join(", ")),
+                        "Saving keeps the variable reference. Click to detach and edit the value directly.",
+                        None,
+                        detach,
+                    );
+                }
+            }
+
+            if value.is_empty() {
+                state.show_default(widget);
+            } else {
+                Self::set_value(widget, &value);
+            }
+            state.shown.replace(value);
+
+            let restore_state = state.clone();
+            let restore_widget = widget.clone();
+            history.register(state.key.clone(), move |shown, value| {
+                restore_state.restore(&restore_widget, shown, value);
+            });
+
+            let reset_state = state.clone();
+            let reset_widget = widget.clone();
+            state.reset_button.connect_clicked(move |_| {
+                reset_state.reset(&reset_widget);
+            });
+
             if let Some(spin_button) = widget.downcast_ref::<gtk::SpinButton>() {
-                let float_value = value.parse::<f64>().unwrap_or(0.0);
-                spin_button.set_value(float_value);
-                let category = category.to_string();
-                let name = name.to_string();
-                let changed_options = changed_options.clone();
                 spin_button.connect_value_changed(move |sb| {
-                    let mut changes = changed_options.borrow_mut();
-                    let new_value = sb.value().to_string();
-                    changes.insert((category.clone(), name.clone()), new_value);
+                    state.record(sb.value().to_string());
                 });
             } else if let Some(entry) = widget.downcast_ref::<Entry>() {
-                entry.set_text(&value);
-                let category = category.to_string();
-                let name = name.to_string();
-                let changed_options = changed_options.clone();
                 entry.connect_changed(move |entry| {
-                    let mut changes = changed_options.borrow_mut();
-                    let new_value = entry.text().to_string();
-                    changes.insert((category.clone(), name.clone()), new_value);
+                    state.record(entry.text().to_string());
                 });
             } else if let Some(switch) = widget.downcast_ref::<Switch>() {
-                switch.set_active(value == "true");
-                let category = category.to_string();
-                let name = name.to_string();
-                let changed_options = changed_options.clone();
                 switch.connect_active_notify(move |sw| {
-                    let mut changes = changed_options.borrow_mut();
-                    let new_value = sw.is_active().to_string();
-                    changes.insert((category.clone(), name.clone()), new_value);
+                    state.record(sw.is_active().to_string());
                 });
             } else if let Some(color_button) = widget.downcast_ref::<ColorButton>() {
-                if let Some((red, green, blue, alpha)) = config.parse_color(&value) {
-                    color_button.set_rgba(&gdk::RGBA::new(red, green, blue, alpha));
-                }
-                let category = category.to_string();
-                let name = name.to_string();
-                let changed_options = changed_options.clone();
                 color_button.connect_color_set(move |cb| {
-                    let mut changes = changed_options.borrow_mut();
                     let new_color = cb.rgba();
                     let new_value = format!(
                         "rgba({:02X}{:02X}{:02X}{:02X})",
@@ -381,29 +681,13 @@ impl WidgetBuilder {
                         (new_color.blue() * 255.0) as u8,
                         (new_color.alpha() * 255.0) as u8
                     );
-                    changes.insert((category.clone(), name.clone()), new_value);
+                    state.record(new_value);
                 });
             } else if let Some(dropdown) = widget.downcast_ref::<gtk::DropDown>() {
-                let model = dropdown.model().unwrap();
-                for i in 0..model.n_items() {
-                    if let Some(item) = model.item(i) {
-                        if let Some(string_object) = item.downcast_ref::<gtk::StringObject>() {
-                            if string_object.string() == value {
-                                dropdown.set_selected(i);
-                                break;
-                            }
-                        }
-                    }
-                }
-                let category = category.to_string();
-                let name = name.to_string();
-                let changed_options = changed_options.clone();
                 dropdown.connect_selected_notify(move |dd| {
-                    let mut changes = changed_options.borrow_mut();
                     if let Some(selected) = dd.selected_item() {
                         if let Some(string_object) = selected.downcast_ref::<gtk::StringObject>() {
-                            let new_value = string_object.string().to_string();
-                            changes.insert((category.clone(), name.clone()), new_value);
+                            state.record(string_object.string().to_string());
                         }
                     }
                 });
@@ -411,6 +695,93 @@ impl WidgetBuilder {
         }
     }
 
+    pub fn set_value(widget: &Widget, value: &str) {
+        if let Some(spin_button) = widget.downcast_ref::<SpinButton>() {
+            if let Ok(float_value) = value.parse::<f64>() {
+                spin_button.set_value(float_value);
+            }
+        } else if let Some(entry) = widget.downcast_ref::<Entry>() {
+            entry.set_text(value);
+        } else if let Some(switch) = widget.downcast_ref::<Switch>() {
+            switch.set_active(defaults::parse_bool(value));
+        } else if let Some(color_button) = widget.downcast_ref::<ColorButton>() {
+            if let Some((red, green, blue, alpha)) = HyprlandConfig::new().parse_color(value) {
+                color_button.set_rgba(&gdk::RGBA::new(red, green, blue, alpha));
+            }
+        } else if let Some(dropdown) = widget.downcast_ref::<gtk::DropDown>() {
+            let model = dropdown.model().unwrap();
+            for i in 0..model.n_items() {
+                if let Some(item) = model.item(i) {
+                    if let Some(string_object) = item.downcast_ref::<gtk::StringObject>() {
+                        if string_object.string() == value {
+                            dropdown.set_selected(i);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn bind_value(
+        widget: &Widget,
+        label: &str,
+        tooltip: &str,
+        confirmation: Option<String>,
+        detach: impl Fn() + 'static,
+    ) -> Option<Button> {
+        let row = widget.parent().and_downcast::<Box>()?;
+
+        let bound_button = Button::with_label(label);
+        bound_button.set_has_frame(false);
+        bound_button.set_opacity(0.7);
+        bound_button.set_valign(gtk::Align::Center);
+        bound_button.set_tooltip_text(Some(tooltip));
+        row.insert_child_after(&bound_button, widget.prev_sibling().as_ref());
+        widget.set_sensitive(false);
+
+        let widget = widget.clone();
+        let detach = Rc::new(detach);
+        bound_button.connect_clicked(move |button| {
+            let release = {
+                let widget = widget.clone();
+                let row = row.clone();
+                let button = button.clone();
+                let detach = detach.clone();
+                move || {
+                    widget.set_sensitive(true);
+                    row.remove(&button);
+                    detach();
+                }
+            };
+
+            match &confirmation {
+                Some(text) => {
+                    let dialog = MessageDialog::builder()
+                        .message_type(gtk::MessageType::Question)
+                        .buttons(gtk::ButtonsType::OkCancel)
+                        .title("Replace Expression")
+                        .text(text)
+                        .modal(true)
+                        .build();
+                    if let Some(window) = button.root().and_downcast::<gtk::Window>() {
+                        dialog.set_transient_for(Some(&window));
+                    }
+                    dialog.connect_response(move |dialog, response| {
+                        if response == gtk::ResponseType::Ok {
+                            release();
+                        }
+                        dialog.close();
+                    });
+                    dialog.show();
+                }
+                None => release(),
+            }
+        });
+
+        Some(bound_button)
+    }
+
     pub fn extract_value(&self, config: &HyprlandConfig, category: &str, name: &str) -> String {
         let mut value = String::new();
         let parts: Vec<&str> = name.split(':').collect();
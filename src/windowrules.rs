@@ -0,0 +1,608 @@
+use gtk::{
+    gio, glib, prelude::*, Box, Button, DropDown, Entry, Label, ListBox, Orientation,
+    ScrolledWindow, SelectionMode, StringList,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::fs;
+use std::rc::Rc;
+
+use crate::clients::{fetch_clients, is_testable_matcher, parse_clients, rule_matches, Client};
+use crate::directives::{self, Block, Directive};
+use crate::rules::{
+    effect_placeholder, is_window_rule_keyword, matcher_placeholder, RuleSyntax, WindowRule,
+    WINDOW_RULE_EFFECTS, WINDOW_RULE_MATCHERS,
+};
+use crate::widgets::WidgetBuilder;
+
+#[derive(Clone)]
+enum RuleSource {
+    Line(Directive),
+    Block(Block),
+}
+
+#[derive(Clone)]
+struct RuleEntry {
+    rule: WindowRule,
+    source: RuleSource,
+}
+
+#[derive(Clone)]
+pub struct WindowRulesPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    clients_list: ListBox,
+    snapshot_label: Label,
+    rules: Rc<RefCell<Vec<RuleEntry>>>,
+    clients: Rc<RefCell<Vec<Client>>>,
+    match_labels: Rc<RefCell<Vec<Label>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl WindowRulesPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Window Rules",
+            "Effects applied to windows matching the given properties.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Window Rule");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        WidgetBuilder::add_section(
+            &container,
+            "Client Snapshot",
+            "Load clients from Hyprland or from a saved `hyprctl clients -j` file to test matchers.",
+            first_section.clone(),
+        );
+
+        let snapshot_box = Box::new(Orientation::Horizontal, 5);
+        let live_button = Button::with_label("Load from Hyprland");
+        let file_button = Button::with_label("Load from File");
+        let snapshot_label = Label::new(Some("No snapshot loaded"));
+        snapshot_label.set_opacity(0.7);
+        snapshot_label.set_margin_start(10);
+        snapshot_box.append(&live_button);
+        snapshot_box.append(&file_button);
+        snapshot_box.append(&snapshot_label);
+        container.append(&snapshot_box);
+
+        let clients_list = ListBox::new();
+        clients_list.set_selection_mode(SelectionMode::None);
+        clients_list.set_margin_top(10);
+        container.append(&clients_list);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            clients_list,
+            snapshot_label,
+            rules: Rc::new(RefCell::new(Vec::new())),
+            clients: Rc::new(RefCell::new(Vec::new())),
+            match_labels: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        live_button.connect_clicked(move |button| {
+            let page = page_clone.clone();
+            let button = button.clone();
+            button.set_sensitive(false);
+            glib::MainContext::default().spawn_local(async move {
+                let json = gio::spawn_blocking(fetch_clients)
+                    .await
+                    .unwrap_or_else(|_| Err("Fetching clients panicked".to_string()));
+                page.load_clients(json, "Hyprland");
+                button.set_sensitive(true);
+            });
+        });
+
+        let page_clone = page.clone();
+        file_button.connect_clicked(move |_| {
+            let page = page_clone.clone();
+            glib::MainContext::default().spawn_local(async move {
+                let parent = page.scrolled_window.root().and_downcast::<gtk::Window>();
+                let file_chooser = gtk::FileChooserDialog::new(
+                    Some("Load Client Snapshot"),
+                    parent.as_ref(),
+                    gtk::FileChooserAction::Open,
+                    &[
+                        ("Cancel", gtk::ResponseType::Cancel),
+                        ("Open", gtk::ResponseType::Accept),
+                    ],
+                );
+
+                if file_chooser.run_future().await == gtk::ResponseType::Accept {
+                    if let Some(path) = file_chooser.file().and_then(|file| file.path()) {
+                        let content = fs::read_to_string(&path).map_err(|e| e.to_string());
+                        page.load_clients(content, &path.display().to_string());
+                    }
+                }
+                file_chooser.close();
+            });
+        });
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let source = match page_clone.rules.borrow().last().map(|e| &e.source) {
+                Some(RuleSource::Block(_)) => RuleSource::Block(Block {
+                    file: 0,
+                    line: None,
+                    entries: Vec::new(),
+                }),
+                Some(RuleSource::Line(directive)) => {
+                    RuleSource::Line(Directive::new(&directive.keyword, "", 0, None))
+                }
+                None => RuleSource::Line(Directive::new("windowrulev2", "", 0, None)),
+            };
+            let syntax = match source {
+                RuleSource::Block(_) => RuleSyntax::Block,
+                RuleSource::Line(_) => RuleSyntax::Matchers,
+            };
+            page_clone.rules.borrow_mut().push(RuleEntry {
+                rule: WindowRule::new(syntax),
+                source,
+            });
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        let mut rules: Vec<RuleEntry> = directives::collect(config, &is_window_rule_keyword)
+            .into_iter()
+            .map(|directive| RuleEntry {
+                rule: WindowRule::parse(&directive.keyword, &directive.value),
+                source: RuleSource::Line(directive),
+            })
+            .collect();
+        rules.extend(
+            directives::collect_blocks(config, "windowrule")
+                .into_iter()
+                .map(|block| RuleEntry {
+                    rule: WindowRule::parse_block(&block.entries),
+                    source: RuleSource::Block(block),
+                }),
+        );
+        *self.rules.borrow_mut() = rules;
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if !self.has_changes() {
+            return;
+        }
+
+        let mut lines = Vec::new();
+        let mut blocks = Vec::new();
+        for entry in self.rules.borrow().iter() {
+            match &entry.source {
+                RuleSource::Line(directive) => {
+                    let mut directive = directive.clone();
+                    if WindowRule::parse(&directive.keyword, &directive.value) != entry.rule {
+                        directive.value = entry.rule.value();
+                    }
+                    lines.push(directive);
+                }
+                RuleSource::Block(block) => {
+                    let mut block = block.clone();
+                    if WindowRule::parse_block(&block.entries) != entry.rule {
+                        block.entries = entry.rule.block_entries();
+                    }
+                    blocks.push(block);
+                }
+            }
+        }
+
+        directives::apply(config, &is_window_rule_keyword, &lines);
+        directives::apply_blocks(config, "windowrule", &blocks);
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+        self.match_labels.borrow_mut().clear();
+
+        let count = self.rules.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+
+        self.refresh_matches();
+    }
+
+    fn load_clients(&self, json: Result<String, String>, source: &str) {
+        match json.and_then(|json| parse_clients(&json)) {
+            Ok(clients) => {
+                self.snapshot_label
+                    .set_text(&format!("{} clients from {}", clients.len(), source));
+                self.snapshot_label.remove_css_class("error");
+                *self.clients.borrow_mut() = clients;
+            }
+            Err(e) => {
+                self.snapshot_label
+                    .set_text(&format!("Failed to load clients: {}", e));
+                self.snapshot_label.add_css_class("error");
+                self.clients.borrow_mut().clear();
+            }
+        }
+        self.refresh_matches();
+    }
+
+    fn refresh_matches(&self) {
+        while let Some(row) = self.clients_list.first_child() {
+            self.clients_list.remove(&row);
+        }
+
+        let rules = self.rules.borrow();
+        let clients = self.clients.borrow();
+        let mut matched_rules: Vec<Vec<String>> = vec![Vec::new(); clients.len()];
+
+        for (index, label) in self.match_labels.borrow().iter().enumerate() {
+            let rule = &rules[index].rule;
+            label.remove_css_class("error");
+            label.remove_css_class("success");
+
+            if clients.is_empty() {
+                label.set_visible(false);
+                continue;
+            }
+            label.set_visible(true);
+
+            let mut matched = Vec::new();
+            let mut error = None;
+            for (client_index, client) in clients.iter().enumerate() {
+                match rule_matches(rule, client) {
+                    Ok(true) => {
+                        matched.push(client.class.clone());
+                        matched_rules[client_index].push(format!("#{}", index + 1));
+                    }
+                    Ok(false) => {}
+                    Err(e) => {
+                        error = Some(e);
+                        break;
+                    }
+                }
+            }
+
+            let untested: Vec<&str> = rule
+                .matchers
+                .iter()
+                .map(|(matcher, _)| matcher.as_str())
+                .filter(|matcher| !is_testable_matcher(matcher))
+                .collect();
+            let mut text = match error {
+                Some(e) => {
+                    label.add_css_class("error");
+                    e
+                }
+                None if matched.is_empty() => "Matches no clients".to_string(),
+                None => {
+                    label.add_css_class("success");
+                    format!("Matches: {}", matched.join(", "))
+                }
+            };
+            if !untested.is_empty() {
+                text.push_str(&format!(" (not tested: {})", untested.join(", ")));
+            }
+            label.set_text(&text);
+        }
+
+        for (client, rules) in clients.iter().zip(matched_rules) {
+            let hbox = Box::new(Orientation::Horizontal, 10);
+            hbox.set_margin_start(5);
+            hbox.set_margin_end(5);
+            hbox.set_margin_top(5);
+            hbox.set_margin_bottom(5);
+
+            let client_label = Label::new(Some(&client.label()));
+            client_label.set_hexpand(true);
+            client_label.set_halign(gtk::Align::Start);
+            client_label.set_ellipsize(gtk::pango::EllipsizeMode::End);
+
+            let rules_label = Label::new(Some(&if rules.is_empty() {
+                "No rules".to_string()
+            } else {
+                format!("Rules {}", rules.join(", "))
+            }));
+            if rules.is_empty() {
+                rules_label.set_opacity(0.7);
+            } else {
+                rules_label.add_css_class("success");
+            }
+
+            hbox.append(&client_label);
+            hbox.append(&rules_label);
+            self.clients_list.append(&hbox);
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut WindowRule)) {
+        if let Some(entry) = self.rules.borrow_mut().get_mut(index) {
+            edit(&mut entry.rule);
+        }
+        *self.changed.borrow_mut() = true;
+        self.refresh_matches();
+    }
+
+    fn edit_and_refresh(&self, index: usize, edit: impl Fn(&mut WindowRule)) {
+        self.update(index, edit);
+        self.refresh();
+    }
+
+    fn same_syntax(&self, a: usize, b: usize) -> bool {
+        let rules = self.rules.borrow();
+        match (rules.get(a), rules.get(b)) {
+            (Some(a), Some(b)) => matches!(
+                (&a.source, &b.source),
+                (RuleSource::Line(_), RuleSource::Line(_))
+                    | (RuleSource::Block(_), RuleSource::Block(_))
+            ),
+            _ => false,
+        }
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let entry = self.rules.borrow()[index].clone();
+        let rule = entry.rule;
+
+        let vbox = Box::new(Orientation::Vertical, 5);
+        vbox.set_margin_start(5);
+        vbox.set_margin_end(5);
+        vbox.set_margin_top(5);
+        vbox.set_margin_bottom(5);
+
+        let header = Box::new(Orientation::Horizontal, 5);
+
+        let syntax_label = Label::new(Some(&match &entry.source {
+            RuleSource::Line(directive) => directive.keyword.clone(),
+            RuleSource::Block(_) => "windowrule { }".to_string(),
+        }));
+        syntax_label.set_opacity(0.7);
+        header.append(&syntax_label);
+
+        if rule.syntax == RuleSyntax::Block {
+            let name_entry = Entry::new();
+            name_entry.set_placeholder_text(Some("Rule name"));
+            name_entry.set_text(&rule.name);
+            header.append(&name_entry);
+
+            let page = self.clone();
+            name_entry.connect_changed(move |entry| {
+                let name = entry.text().to_string();
+                page.update(index, |r| r.name = name.clone());
+            });
+        }
+
+        let spacer = Box::new(Orientation::Horizontal, 0);
+        spacer.set_hexpand(true);
+        header.append(&spacer);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0 && self.same_syntax(index - 1, index));
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(self.same_syntax(index, index + 1));
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        header.append(&up_button);
+        header.append(&down_button);
+        header.append(&delete_button);
+        vbox.append(&header);
+
+        let effect_names: Vec<&str> = WINDOW_RULE_EFFECTS.iter().map(|(name, _)| *name).collect();
+        for (effect_index, (effect, args)) in rule.effects.iter().enumerate() {
+            let hbox = Box::new(Orientation::Horizontal, 5);
+            hbox.set_margin_start(10);
+
+            let label = Label::new(Some("Effect"));
+            label.set_width_chars(8);
+            label.set_xalign(0.0);
+
+            let effect_dropdown = searchable_dropdown(&effect_names, effect);
+            let args_entry = Entry::new();
+            args_entry.set_placeholder_text(Some(effect_placeholder(effect)));
+            args_entry.set_text(args);
+            args_entry.set_hexpand(true);
+
+            hbox.append(&label);
+            hbox.append(&effect_dropdown);
+            hbox.append(&args_entry);
+
+            if rule.syntax == RuleSyntax::Block && rule.effects.len() > 1 {
+                let remove_button = Button::from_icon_name("list-remove-symbolic");
+                remove_button.set_has_frame(false);
+                hbox.append(&remove_button);
+
+                let page = self.clone();
+                remove_button.connect_clicked(move |_| {
+                    page.edit_and_refresh(index, |r| {
+                        r.effects.remove(effect_index);
+                    });
+                });
+            }
+
+            let page = self.clone();
+            let entry_clone = args_entry.clone();
+            effect_dropdown.connect_selected_notify(move |dd| {
+                let effect = selected_string(dd);
+                entry_clone.set_placeholder_text(Some(effect_placeholder(&effect)));
+                page.update(index, |r| r.effects[effect_index].0 = effect.clone());
+            });
+
+            let page = self.clone();
+            args_entry.connect_changed(move |entry| {
+                let args = entry.text().to_string();
+                page.update(index, |r| r.effects[effect_index].1 = args.clone());
+            });
+
+            vbox.append(&hbox);
+        }
+
+        let matcher_names: Vec<&str> = WINDOW_RULE_MATCHERS.iter().map(|(name, _)| *name).collect();
+        for (matcher_index, (matcher, value)) in rule.matchers.iter().enumerate() {
+            let hbox = Box::new(Orientation::Horizontal, 5);
+            hbox.set_margin_start(10);
+
+            let label = Label::new(Some("Match"));
+            label.set_width_chars(8);
+            label.set_xalign(0.0);
+
+            let matcher_dropdown = searchable_dropdown(&matcher_names, matcher);
+            let value_entry = Entry::new();
+            value_entry.set_placeholder_text(Some(matcher_placeholder(matcher)));
+            value_entry.set_text(value);
+            value_entry.set_hexpand(true);
+
+            let remove_button = Button::from_icon_name("list-remove-symbolic");
+            remove_button.set_has_frame(false);
+            remove_button.set_sensitive(rule.matchers.len() > 1);
+
+            hbox.append(&label);
+            hbox.append(&matcher_dropdown);
+            hbox.append(&value_entry);
+            hbox.append(&remove_button);
+
+            let page = self.clone();
+            let entry_clone = value_entry.clone();
+            matcher_dropdown.connect_selected_notify(move |dd| {
+                let matcher = selected_string(dd);
+                entry_clone.set_placeholder_text(Some(matcher_placeholder(&matcher)));
+                page.update(index, |r| r.matchers[matcher_index].0 = matcher.clone());
+            });
+
+            let page = self.clone();
+            value_entry.connect_changed(move |entry| {
+                let value = entry.text().to_string();
+                page.update(index, |r| r.matchers[matcher_index].1 = value.clone());
+            });
+
+            let page = self.clone();
+            remove_button.connect_clicked(move |_| {
+                page.edit_and_refresh(index, |r| {
+                    r.matchers.remove(matcher_index);
+                });
+            });
+
+            vbox.append(&hbox);
+        }
+
+        let actions = Box::new(Orientation::Horizontal, 5);
+        actions.set_margin_start(10);
+
+        let add_matcher_button = Button::with_label("Add Matcher");
+        actions.append(&add_matcher_button);
+
+        let page = self.clone();
+        add_matcher_button.connect_clicked(move |_| {
+            page.edit_and_refresh(index, |r| {
+                r.matchers.push(("title".to_string(), String::new()));
+            });
+        });
+
+        if rule.syntax == RuleSyntax::Block {
+            let add_effect_button = Button::with_label("Add Effect");
+            actions.append(&add_effect_button);
+
+            let page = self.clone();
+            add_effect_button.connect_clicked(move |_| {
+                page.edit_and_refresh(index, |r| {
+                    r.effects.push(("float".to_string(), String::new()));
+                });
+            });
+        }
+
+        vbox.append(&actions);
+
+        let match_label = Label::new(None);
+        match_label.set_halign(gtk::Align::Start);
+        match_label.set_margin_start(10);
+        match_label.set_wrap(true);
+        vbox.append(&match_label);
+        self.match_labels.borrow_mut().push(match_label);
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        vbox
+    }
+}
+
+fn searchable_dropdown(items: &[&str], value: &str) -> DropDown {
+    let mut items = items.to_vec();
+    if !items.contains(&value) {
+        items.push(value);
+    }
+    let dropdown = DropDown::new(
+        Some(StringList::new(&items)),
+        Some(gtk::PropertyExpression::new(
+            gtk::StringObject::static_type(),
+            None::<gtk::Expression>,
+            "string",
+        )),
+    );
+    dropdown.set_enable_search(true);
+    if let Some(position) = items.iter().position(|item| *item == value) {
+        dropdown.set_selected(position as u32);
+    }
+    dropdown
+}
+
+fn selected_string(dropdown: &DropDown) -> String {
+    dropdown
+        .selected_item()
+        .and_then(|item| item.downcast::<gtk::StringObject>().ok())
+        .map(|item| item.string().to_string())
+        .unwrap_or_default()
+}
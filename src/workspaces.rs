@@ -0,0 +1,413 @@
+use gtk::{
+    prelude::*, Box, Button, CheckButton, DropDown, Entry, Expander, ListBox, Orientation,
+    ScrolledWindow, SelectionMode, SpinButton, Widget,
+};
+use hyprparser::HyprlandConfig;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::directives::{self, Directive};
+use crate::widgets::WidgetBuilder;
+
+#[derive(Clone, Copy, PartialEq)]
+enum RuleField {
+    Int,
+    Bool,
+    Text,
+}
+
+const ON_CREATED_EMPTY: &str = "on-created-empty";
+const BOOL_CHOICES: [&str; 3] = ["unset", "true", "false"];
+
+const WORKSPACE_FIELDS: [(&str, &str, &str, RuleField); 12] = [
+    (
+        "monitor",
+        "Monitor",
+        "Binds the workspace to a monitor.",
+        RuleField::Text,
+    ),
+    (
+        "default",
+        "Default",
+        "Makes this the default workspace of its monitor.",
+        RuleField::Bool,
+    ),
+    (
+        "persistent",
+        "Persistent",
+        "Keeps the workspace alive even when it is empty.",
+        RuleField::Bool,
+    ),
+    (
+        "gapsin",
+        "Gaps In",
+        "Gaps between windows on this workspace.",
+        RuleField::Int,
+    ),
+    (
+        "gapsout",
+        "Gaps Out",
+        "Gaps between windows and monitor edges on this workspace.",
+        RuleField::Int,
+    ),
+    (
+        "bordersize",
+        "Border Size",
+        "Border size of windows on this workspace.",
+        RuleField::Int,
+    ),
+    (
+        "border",
+        "Border",
+        "Whether to draw borders on this workspace.",
+        RuleField::Bool,
+    ),
+    (
+        "shadow",
+        "Shadow",
+        "Whether to draw shadows on this workspace.",
+        RuleField::Bool,
+    ),
+    (
+        "rounding",
+        "Rounding",
+        "Whether to round window corners on this workspace.",
+        RuleField::Bool,
+    ),
+    (
+        "decorate",
+        "Decorate",
+        "Whether to draw window decorations on this workspace.",
+        RuleField::Bool,
+    ),
+    (
+        "on-created-empty",
+        "On Created Empty",
+        "Command to run when the workspace is created empty.",
+        RuleField::Text,
+    ),
+    (
+        "defaultName",
+        "Default Name",
+        "Default name of the workspace.",
+        RuleField::Text,
+    ),
+];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WorkspaceRule {
+    pub selector: String,
+    pub rules: Vec<(String, String)>,
+}
+
+impl WorkspaceRule {
+    pub fn parse(value: &str) -> Self {
+        let parts: Vec<&str> = value.split(',').collect();
+        let selector = parts.first().unwrap_or(&"").trim().to_string();
+        let mut rules = Vec::new();
+        for (index, part) in parts.iter().enumerate().skip(1) {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            if let Some(command) = part
+                .strip_prefix(ON_CREATED_EMPTY)
+                .and_then(|rest| rest.trim_start().strip_prefix(':'))
+            {
+                let command = std::iter::once(command)
+                    .chain(parts[index + 1..].iter().copied())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                rules.push((ON_CREATED_EMPTY.to_string(), command.trim().to_string()));
+                break;
+            }
+            rules.push(match part.split_once(':') {
+                Some((key, value)) => (key.trim().to_string(), value.trim().to_string()),
+                None => (part.to_string(), String::new()),
+            });
+        }
+        Self { selector, rules }
+    }
+
+    pub fn value(&self) -> String {
+        let mut parts = vec![self.selector.clone()];
+        let (commands, rules): (Vec<_>, Vec<_>) = self
+            .rules
+            .iter()
+            .partition(|(key, _)| key == ON_CREATED_EMPTY);
+        for (key, value) in rules.into_iter().chain(commands) {
+            if value.is_empty() {
+                parts.push(key.clone());
+            } else {
+                parts.push(format!("{}:{}", key, value));
+            }
+        }
+        parts.join(", ")
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn set(&mut self, key: &str, value: String) {
+        if value.trim().is_empty() {
+            self.rules.retain(|(k, _)| k != key);
+            return;
+        }
+        match self.rules.iter_mut().find(|(k, _)| k == key) {
+            Some(rule) => rule.1 = value,
+            None => self.rules.push((key.to_string(), value)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct WorkspacesPage {
+    pub scrolled_window: ScrolledWindow,
+    list: ListBox,
+    rules: Rc<RefCell<Vec<Directive>>>,
+    changed: Rc<RefCell<bool>>,
+}
+
+impl WorkspacesPage {
+    pub fn new() -> Self {
+        let scrolled_window = ScrolledWindow::new();
+        scrolled_window.set_vexpand(false);
+        scrolled_window.set_propagate_natural_height(true);
+
+        let container = Box::new(Orientation::Vertical, 0);
+        container.set_margin_start(20);
+        container.set_margin_end(20);
+        container.set_margin_top(20);
+        container.set_margin_bottom(20);
+
+        scrolled_window.set_child(Some(&container));
+
+        let first_section = Rc::new(RefCell::new(true));
+
+        WidgetBuilder::add_section(
+            &container,
+            "Workspace Rules",
+            "Per-workspace monitor bindings, gaps and decorations.",
+            first_section.clone(),
+        );
+
+        let list = ListBox::new();
+        list.set_selection_mode(SelectionMode::None);
+        container.append(&list);
+
+        let add_button = Button::with_label("Add Workspace Rule");
+        add_button.set_halign(gtk::Align::Start);
+        add_button.set_margin_top(10);
+        container.append(&add_button);
+
+        let page = Self {
+            scrolled_window,
+            list,
+            rules: Rc::new(RefCell::new(Vec::new())),
+            changed: Rc::new(RefCell::new(false)),
+        };
+
+        let page_clone = page.clone();
+        add_button.connect_clicked(move |_| {
+            let count = page_clone.rules.borrow().len();
+            page_clone.rules.borrow_mut().push(Directive::new(
+                "workspace",
+                &(count + 1).to_string(),
+                0,
+                None,
+            ));
+            *page_clone.changed.borrow_mut() = true;
+            page_clone.refresh();
+        });
+
+        page
+    }
+
+    pub fn load_config(&self, config: &HyprlandConfig) {
+        *self.rules.borrow_mut() = directives::collect(config, &|k| k == "workspace");
+        *self.changed.borrow_mut() = false;
+        self.refresh();
+    }
+
+    pub fn has_changes(&self) -> bool {
+        *self.changed.borrow()
+    }
+
+    pub fn apply_changes(&self, config: &mut HyprlandConfig) {
+        if self.has_changes() {
+            directives::apply(config, &|k| k == "workspace", &self.rules.borrow());
+        }
+    }
+
+    fn refresh(&self) {
+        while let Some(row) = self.list.first_child() {
+            self.list.remove(&row);
+        }
+
+        let count = self.rules.borrow().len();
+        for index in 0..count {
+            let row = self.build_row(index);
+            self.list.append(&row);
+        }
+    }
+
+    fn update(&self, index: usize, edit: impl Fn(&mut WorkspaceRule)) {
+        if let Some(directive) = self.rules.borrow_mut().get_mut(index) {
+            let mut rule = WorkspaceRule::parse(&directive.value);
+            edit(&mut rule);
+            directive.value = rule.value();
+        }
+        *self.changed.borrow_mut() = true;
+    }
+
+    fn build_row(&self, index: usize) -> Box {
+        let rule = WorkspaceRule::parse(&self.rules.borrow()[index].value);
+
+        let vbox = Box::new(Orientation::Vertical, 5);
+        vbox.set_margin_start(5);
+        vbox.set_margin_end(5);
+        vbox.set_margin_top(5);
+        vbox.set_margin_bottom(5);
+
+        let hbox = Box::new(Orientation::Horizontal, 5);
+
+        let selector_entry = Entry::new();
+        selector_entry.set_placeholder_text(Some("Workspace (e.g. 1, name:web, special:scratch)"));
+        selector_entry.set_text(&rule.selector);
+        selector_entry.set_hexpand(true);
+
+        let up_button = Button::from_icon_name("go-up-symbolic");
+        up_button.set_has_frame(false);
+        up_button.set_sensitive(index > 0);
+        let down_button = Button::from_icon_name("go-down-symbolic");
+        down_button.set_has_frame(false);
+        down_button.set_sensitive(index + 1 < self.rules.borrow().len());
+        let delete_button = Button::from_icon_name("user-trash-symbolic");
+        delete_button.set_has_frame(false);
+
+        hbox.append(&selector_entry);
+        hbox.append(&up_button);
+        hbox.append(&down_button);
+        hbox.append(&delete_button);
+
+        let expander = Expander::new(Some("Rules"));
+        let form = Box::new(Orientation::Vertical, 0);
+        expander.set_child(Some(&form));
+
+        let mut options: HashMap<String, Widget> = HashMap::new();
+        for (name, label, description, field) in WORKSPACE_FIELDS {
+            let value = rule.get(name).unwrap_or_default();
+            let field =
+                if field == RuleField::Int && !value.is_empty() && value.parse::<i32>().is_err() {
+                    RuleField::Text
+                } else {
+                    field
+                };
+
+            match field {
+                RuleField::Int => {
+                    WidgetBuilder::add_int_option(&form, &mut options, name, label, description)
+                }
+                RuleField::Bool => WidgetBuilder::add_choice_option(
+                    &form,
+                    &mut options,
+                    name,
+                    label,
+                    description,
+                    &BOOL_CHOICES,
+                ),
+                RuleField::Text => {
+                    WidgetBuilder::add_string_option(&form, &mut options, name, label, description)
+                }
+            }
+
+            let page = self.clone();
+            if let Some(spin_button) = options[name].downcast_ref::<SpinButton>() {
+                let set_check = CheckButton::new();
+                set_check.set_tooltip_text(Some("Set this rule; unset rules are left out"));
+                set_check.set_active(!value.is_empty());
+                spin_button.set_sensitive(!value.is_empty());
+                spin_button.set_value(value.parse::<f64>().unwrap_or(0.0));
+                if let Some(parent) = spin_button.parent() {
+                    if let Some(hbox) = parent.downcast_ref::<Box>() {
+                        hbox.insert_child_after(&set_check, spin_button.prev_sibling().as_ref());
+                    }
+                }
+
+                let page_clone = page.clone();
+                spin_button.connect_value_changed(move |sb| {
+                    let value = sb.value_as_int().to_string();
+                    page_clone.update(index, |r| r.set(name, value.clone()));
+                });
+                let spin_button = spin_button.clone();
+                set_check.connect_toggled(move |check| {
+                    spin_button.set_sensitive(check.is_active());
+                    let value = if check.is_active() {
+                        spin_button.value_as_int().to_string()
+                    } else {
+                        String::new()
+                    };
+                    page.update(index, |r| r.set(name, value.clone()));
+                });
+            } else if let Some(dropdown) = options[name].downcast_ref::<DropDown>() {
+                let selected = match value {
+                    "" => 0,
+                    "true" | "1" | "yes" | "on" => 1,
+                    _ => 2,
+                };
+                dropdown.set_selected(selected);
+                dropdown.connect_selected_notify(move |dd| {
+                    let value = match dd.selected() {
+                        1 => "true",
+                        2 => "false",
+                        _ => "",
+                    };
+                    page.update(index, |r| r.set(name, value.to_string()));
+                });
+            } else if let Some(entry) = options[name].downcast_ref::<Entry>() {
+                entry.set_text(value);
+                entry.connect_changed(move |entry| {
+                    let value = entry.text().to_string();
+                    page.update(index, |r| r.set(name, value.clone()));
+                });
+            }
+        }
+
+        vbox.append(&hbox);
+        vbox.append(&expander);
+
+        let page = self.clone();
+        selector_entry.connect_changed(move |entry| {
+            let selector = entry.text().to_string();
+            page.update(index, |r| r.selector = selector.clone());
+        });
+
+        let page = self.clone();
+        up_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index - 1, index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        down_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().swap(index, index + 1);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        let page = self.clone();
+        delete_button.connect_clicked(move |_| {
+            page.rules.borrow_mut().remove(index);
+            *page.changed.borrow_mut() = true;
+            page.refresh();
+        });
+
+        vbox
+    }
+}